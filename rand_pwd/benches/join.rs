@@ -0,0 +1,40 @@
+use rand_pwd::*;
+use criterion::{ black_box, criterion_group, criterion_main, Criterion };
+
+
+/// Up to `SMALL_LEN` characters are drawn sequentially, longer passwords in chunks on rayon
+fn join(c: &mut Criterion) {
+
+    let mut small = RandPwd::new(12, 2, 2).unwrap();
+    c.bench_function("join 16", |b| b.iter(|| black_box(&mut small).join()));
+
+    let mut medium = RandPwd::new(200, 28, 28).unwrap();
+    c.bench_function("join 256", |b| b.iter(|| black_box(&mut medium).join()));
+
+    let mut above = RandPwd::new(201, 28, 28).unwrap();
+    c.bench_function("join 257", |b| b.iter(|| black_box(&mut above).join()));
+
+    let mut large = RandPwd::new(80_000, 10_000, 10_000).unwrap();
+    c.bench_function("join 100_000", |b| b.iter(|| black_box(&mut large).join()));
+
+    let mut seeded = RandPwd::with_seed(12, 2, 2, 42).unwrap();
+    c.bench_function("join 16 seeded", |b| b.iter(|| black_box(&mut seeded).join()));
+
+}
+
+
+/// The default pools are cached, the custom ones are filtered on every draw
+fn generate_many(c: &mut Criterion) {
+
+    let many = RandPwd::new(12, 2, 2).unwrap();
+    c.bench_function("generate_many 1000", |b| b.iter(|| black_box(&many).generate_many(black_box(1000))));
+
+    let mut custom = RandPwd::new(12, 2, 2).unwrap();
+    custom.with_charset(Charset::custom("abcdefghijklmnopqrstuvwxyz", "!?-_", "0123456789")).unwrap();
+    c.bench_function("generate_many 1000 custom", |b| b.iter(|| black_box(&custom).generate_many(black_box(1000))));
+
+}
+
+
+criterion_group!(benches, join, generate_many);
+criterion_main!(benches);
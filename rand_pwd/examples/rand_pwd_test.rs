@@ -10,7 +10,7 @@ fn main() {
     let mut r_p;
 
     if demands.is_empty() {
-        r_p = RandPwd::new(10, 2, 3);
+        r_p = RandPwd::new(10, 2, 3).unwrap();
         r_p.join();
         println!("{}", r_p);
     } else {
@@ -18,7 +18,7 @@ fn main() {
         let sbl_cnt = demands[1].clone();
         let num_cnt = demands[2].clone();
 
-        r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt);
+        r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt).unwrap();
         r_p.join();
         println!("{}", r_p);
     }
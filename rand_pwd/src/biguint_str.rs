@@ -0,0 +1,41 @@
+//! (De)serialize `BigUint` as a decimal string, so any count survives the round trip
+//! through formats like JSON whose numbers are limited to `f64`
+use alloc::string::String;
+use num_bigint::BigUint;
+use serde::{ de, Deserialize, Deserializer, Serializer };
+
+
+#[inline]
+pub(crate) fn serialize<S: Serializer>(val: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(val)
+}
+
+
+#[inline]
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+    String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+}
+
+
+/// (De)serialize `Option<BigUint>` the same way
+pub(crate) mod option {
+
+    use alloc::string::{ String, ToString };
+    use num_bigint::BigUint;
+    use serde::{ de, Deserialize, Deserializer, Serialize, Serializer };
+
+
+    #[inline]
+    pub(crate) fn serialize<S: Serializer>(val: &Option<BigUint>, serializer: S) -> Result<S::Ok, S::Error> {
+        val.as_ref().map(ToString::to_string).serialize(serializer)
+    }
+
+
+    #[inline]
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<BigUint>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| s.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+
+}
@@ -0,0 +1,803 @@
+use crate::{ RandPwd, Charset, PasswordError, CasePolicy, CheckKind, TypePrefer, Layout };
+use crate::prelude::*;
+
+
+/// Builder of `RandPwd`, the counts of letters, symbols and numbers default to zero
+/// # Example
+/// ```
+/// use rand_pwd::RandPwd;
+/// let mut r_p = RandPwd::builder()
+///                 .letters(16)
+///                 .symbols(2)
+///                 .build()
+///                 .unwrap();
+/// r_p.join();
+/// assert_eq!(r_p.len(), 18);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RandPwdBuilder {
+    ltr_cnt: BigUint,
+    sbl_cnt: BigUint,
+    num_cnt: BigUint,
+    length: Option<BigUint>,
+    sbl_ratio: Option<f64>,
+    num_ratio: Option<f64>,
+    max_sbl: Option<BigUint>,
+    max_num: Option<BigUint>,
+    seed: Option<u64>,
+    exclude_ambiguous: bool,
+    excluded: Option<String>,
+    charset: Option<Charset>,
+    letters: Option<String>,
+    symbols: Option<String>,
+    numbers: Option<String>,
+    typeability: Option<TypePrefer>,
+    layout: Layout,
+    min_upper: BigUint,
+    min_lower: BigUint,
+    require_letter: bool,
+    require_symbol: bool,
+    require_number: bool,
+    no_repeats: bool,
+    no_sequences: bool,
+    no_edge_symbols: bool,
+    min_distinct_sbl: usize,
+    min_distinct_num: usize,
+    all_distinct: bool,
+    ascii_only: bool,
+    keep_order: bool,
+    sequential: bool,
+    bidi_safe: bool,
+    leet: f64,
+    case: CasePolicy,
+    checksum: Option<CheckKind>,
+    prefix: String,
+    suffix: String,
+    error: Option<PasswordError>,
+}
+
+
+impl RandPwdBuilder {
+
+    /// Return a builder with all the counts set to zero
+    #[inline]
+    pub fn new() -> Self {
+        RandPwdBuilder::default()
+    }
+
+
+    /// Set the count of letters
+    #[inline]
+    pub fn letters<T: ToBigUint>(mut self, val: T) -> Self {
+        self.ltr_cnt = self.count("ltr", val);
+        self
+    }
+
+
+    /// Set the count of symbols
+    #[inline]
+    pub fn symbols<T: ToBigUint>(mut self, val: T) -> Self {
+        self.sbl_cnt = self.count("sbl", val);
+        self
+    }
+
+
+    /// Set the count of numbers
+    #[inline]
+    pub fn numbers<T: ToBigUint>(mut self, val: T) -> Self {
+        self.num_cnt = self.count("num", val);
+        self
+    }
+
+
+    /// Set the total length, the letters take what the symbols and numbers leave
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::builder().length(16).symbols(2).numbers(3).build().unwrap();
+    /// assert_eq!(r_p.get_cnt("ltr").unwrap(), &11u8.into());
+    /// ```
+    #[inline]
+    pub fn length<T: ToBigUint>(mut self, val: T) -> Self {
+        self.length = Some(self.count("len", val));
+        self
+    }
+
+
+    /// Make `val` of the length symbols instead of a fixed count
+    ///
+    /// The ratio is a fraction in `0.0..=1.0` of `length`, or of the total of the counts if it's
+    /// not set, and it's rounded to the nearest count, halves up, at `build`.
+    /// The letters take the remainder like with `length`.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::builder().length(12).symbol_ratio(0.2).number_ratio(0.25).build().unwrap();
+    /// assert_eq!(r_p.get_cnt("sbl").unwrap(), &2u8.into());
+    /// assert_eq!(r_p.get_cnt("num").unwrap(), &3u8.into());
+    /// assert_eq!(r_p.get_cnt("ltr").unwrap(), &7u8.into());
+    ///
+    /// let r_p = RandPwd::builder().length(12).symbol_ratio(1.5).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::InvalidCount("sbl"));
+    /// ```
+    #[inline]
+    pub fn symbol_ratio(mut self, val: f64) -> Self {
+        self.sbl_ratio = self.ratio("sbl", val);
+        self
+    }
+
+
+    /// Make `val` of the length numbers instead of a fixed count, see `symbol_ratio`
+    #[inline]
+    pub fn number_ratio(mut self, val: f64) -> Self {
+        self.num_ratio = self.ratio("num", val);
+        self
+    }
+
+
+    /// Draw the count of symbols from `0..=val` for every password instead of a fixed count
+    ///
+    /// The length stays the same, the letters fill what the symbols leave. A count set with
+    /// `symbols`, or the one of `require_symbol`, becomes the lowest count that can be drawn.
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset };
+    /// let r_p = RandPwd::builder().length(12).max_symbols(3).max_numbers(3).build().unwrap();
+    /// for pwd in r_p.generate_many(100) {
+    ///     assert_eq!(pwd.len(), 12);
+    ///     assert!(pwd.matches(|ch: char| ch.is_ascii_punctuation()).count() <= 3);
+    ///     assert!(pwd.matches(|ch: char| ch.is_ascii_digit()).count() <= 3);
+    ///     assert!(r_p.matches_policy(&pwd));
+    /// }
+    ///
+    /// // "aa", "a0", "0a" and "00"
+    /// let r_p = RandPwd::builder().length(2).max_numbers(2).with_charset(Charset::custom("a", "", "0")).build().unwrap();
+    /// assert_eq!(r_p.combinations(), Some(4u8.into()));
+    /// ```
+    #[inline]
+    pub fn max_symbols<T: ToBigUint>(mut self, val: T) -> Self {
+        self.max_sbl = Some(self.count("sbl", val));
+        self
+    }
+
+
+    /// Draw the count of numbers from `0..=val` for every password, see `max_symbols`
+    #[inline]
+    pub fn max_numbers<T: ToBigUint>(mut self, val: T) -> Self {
+        self.max_num = Some(self.count("num", val));
+        self
+    }
+
+
+    /// Guarantee at least `val` uppercase letters among the letters
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().letters(12).min_upper(4).min_lower(4).build().unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().matches(char::is_uppercase).count() >= 4);
+    /// assert!(r_p.val().matches(char::is_lowercase).count() >= 4);
+    /// ```
+    #[inline]
+    pub fn min_upper<T: ToBigUint>(mut self, val: T) -> Self {
+        self.min_upper = self.count("upper", val);
+        self
+    }
+
+
+    /// Guarantee at least `val` lowercase letters among the letters
+    #[inline]
+    pub fn min_lower<T: ToBigUint>(mut self, val: T) -> Self {
+        self.min_lower = self.count("lower", val);
+        self
+    }
+
+
+    /// Guarantee at least one letter, whatever the count of letters is
+    ///
+    /// A required category whose count is zero borrows one slot, from the letters beyond
+    /// `min_upper` and `min_lower` first, then from the symbols and the numbers,
+    /// so the length is never changed. A non-zero count already satisfies the requirement.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(8).require_symbol(true).require_number(true).build().unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.category_counts(), (6, 1, 1));
+    ///
+    /// let mut r_p = RandPwd::builder().numbers(4).require_letter(true).build().unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.category_counts(), (1, 0, 3));
+    ///
+    /// // One slot can't hold a symbol and a number
+    /// let r_p = RandPwd::builder().letters(1).require_symbol(true).require_number(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::LengthTooSmall { length: 1u8.into(), required: 2u8.into(), deficit: 1u8.into() });
+    /// ```
+    #[inline]
+    pub fn require_letter(mut self, val: bool) -> Self {
+        self.require_letter = val;
+        self
+    }
+
+
+    /// Guarantee at least one symbol, see `require_letter`
+    #[inline]
+    pub fn require_symbol(mut self, val: bool) -> Self {
+        self.require_symbol = val;
+        self
+    }
+
+
+    /// Guarantee at least one number, see `require_letter`
+    #[inline]
+    pub fn require_number(mut self, val: bool) -> Self {
+        self.require_number = val;
+        self
+    }
+
+
+    /// Never put two identical characters next to each other
+    ///
+    /// The shuffled password is fixed up by swapping, the counts of every category are kept.
+    /// Passphrases are not affected.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(20).with_letters("ab").no_repeats(true).build().unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val() == "abababababababababab" || r_p.val() == "babababababababababa");
+    ///
+    /// // Three `a`s and a number can't be laid out without repeats
+    /// let r_p = RandPwd::builder().letters(3).numbers(1).with_letters("a").no_repeats(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("no_repeats"));
+    /// ```
+    #[inline]
+    pub fn no_repeats(mut self, val: bool) -> Self {
+        self.no_repeats = val;
+        self
+    }
+
+
+    /// Never put three consecutive code points in a row, ascending like `def` or descending like `987`
+    ///
+    /// The shuffled password is fixed up by a bounded number of swaps,
+    /// it's drawn again if that isn't enough. Passphrases are not affected.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().numbers(30).no_sequences(true).build().unwrap();
+    /// r_p.join();
+    /// let pwd = r_p.val().as_bytes();
+    /// assert!(pwd.windows(3).all(|w| !(w[0] + 1 == w[1] && w[1] + 1 == w[2])));
+    /// assert!(pwd.windows(3).all(|w| !(w[0] == w[1] + 1 && w[1] == w[2] + 1)));
+    /// ```
+    #[inline]
+    pub fn no_sequences(mut self, val: bool) -> Self {
+        self.no_sequences = val;
+        self
+    }
+
+
+    /// Never start nor end the password with a symbol
+    ///
+    /// A symbol on an edge of the shuffled password is swapped with a letter or a number
+    /// in between, it's drawn again if a fixup of `no_repeats` or `no_sequences` undoes it.
+    /// The leet substitutes aren't put on the edges either. Passphrases are not affected.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::builder().letters(1).numbers(1).symbols(30).no_edge_symbols(true).build().unwrap();
+    /// for pwd in r_p.generate_many(50) {
+    ///     assert!(!pwd.starts_with(|ch: char| ch.is_ascii_punctuation()));
+    ///     assert!(!pwd.ends_with(|ch: char| ch.is_ascii_punctuation()));
+    ///     assert!(r_p.matches_policy(&pwd));
+    /// }
+    ///
+    /// let r_p = RandPwd::builder().letters(1).symbols(30).no_edge_symbols(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("no_edge_symbols"));
+    /// ```
+    #[inline]
+    pub fn no_edge_symbols(mut self, val: bool) -> Self {
+        self.no_edge_symbols = val;
+        self
+    }
+
+
+    /// Guarantee at least `val` different symbols, they are drawn without replacement
+    /// and the other symbols are drawn as usual
+    ///
+    /// `val` can't be more than the symbol pool has, nor more than the fewest symbols
+    /// a password can have.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// use std::collections::HashSet;
+    /// let r_p = RandPwd::builder().letters(8).symbols(3).numbers(3).min_distinct_symbols(3).min_distinct_numbers(2).build().unwrap();
+    /// for pwd in r_p.generate_many(50) {
+    ///     assert_eq!(pwd.chars().filter(char::is_ascii_punctuation).collect::<HashSet<_>>().len(), 3);
+    ///     assert!(pwd.chars().filter(char::is_ascii_digit).collect::<HashSet<_>>().len() >= 2);
+    ///     assert!(r_p.matches_policy(&pwd));
+    /// }
+    ///
+    /// let r_p = RandPwd::builder().letters(8).symbols(2).min_distinct_symbols(3).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("min_distinct_symbols"));
+    ///
+    /// let r_p = RandPwd::builder().numbers(12).min_distinct_numbers(11).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("min_distinct_numbers"));
+    /// ```
+    #[inline]
+    pub fn min_distinct_symbols(mut self, val: usize) -> Self {
+        self.min_distinct_sbl = val;
+        self
+    }
+
+
+    /// Guarantee at least `val` different numbers, see `min_distinct_symbols`
+    #[inline]
+    pub fn min_distinct_numbers(mut self, val: usize) -> Self {
+        self.min_distinct_num = val;
+        self
+    }
+
+
+    /// Never use a character twice anywhere in the password, unlike `no_repeats` which only
+    /// looks at the neighbors
+    ///
+    /// Every category is drawn without replacement, uniformly whatever the weights,
+    /// so the length can't be more than `alphabet_size` nor a count more than its pool has.
+    /// With a case policy a letter and its other case count as the same letter.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// use std::collections::HashSet;
+    /// let r_p = RandPwd::builder().letters(20).symbols(4).numbers(10).all_distinct(true).build().unwrap();
+    /// for pwd in r_p.generate_many(20) {
+    ///     assert_eq!(pwd.chars().collect::<HashSet<_>>().len(), 34);
+    ///     assert!(r_p.matches_policy(&pwd));
+    /// }
+    ///
+    /// let r_p = RandPwd::builder().numbers(11).all_distinct(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("all_distinct"));
+    /// ```
+    #[inline]
+    pub fn all_distinct(mut self, val: bool) -> Self {
+        self.all_distinct = val;
+        self
+    }
+
+
+    /// Refuse to build if a pool, a custom one included, has a character which isn't ASCII
+    ///
+    /// Every pool is checked, even one without a count, it could be drawn from once a count is set.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::builder().letters(8).with_letters("abcé").ascii_only(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("ascii_only"));
+    ///
+    /// let r_p = RandPwd::builder().letters(8).with_symbols("€").ascii_only(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("ascii_only"));
+    ///
+    /// assert!(RandPwd::builder().length(16).ascii_only(true).build().is_ok());
+    /// ```
+    #[inline]
+    pub fn ascii_only(mut self, val: bool) -> Self {
+        self.ascii_only = val;
+        self
+    }
+
+
+    /// Normalize the case of the letters, see `RandPwd::set_case`
+    /// ```
+    /// use rand_pwd::{ RandPwd, CasePolicy };
+    /// let mut r_p = RandPwd::builder().length(12).case(CasePolicy::Lower).build().unwrap();
+    /// r_p.join();
+    /// assert!(!r_p.val().chars().any(char::is_uppercase));
+    /// ```
+    #[inline]
+    pub fn case(mut self, policy: CasePolicy) -> Self {
+        self.case = policy;
+        self
+    }
+
+
+    /// Substitute some letters by their leet equivalents, see `RandPwd::set_leetify`
+    #[inline]
+    pub fn leetify(mut self, prob: f64) -> Self {
+        self.leet = prob;
+        self
+    }
+
+
+    /// Shuffle the characters, which is the default, or keep them grouped in the order of
+    /// letters, symbols and numbers, like `"ab12"` can only be `"ab12"`
+    ///
+    /// The guaranteed uppercase and lowercase letters follow the other letters in that order.
+    /// The fixups of `no_repeats`, `no_sequences` and `no_edge_symbols` would break the groups up, so they
+    /// can't go without the shuffle. Passphrases and pronounceable passwords are not affected.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(4).symbols(2).numbers(4).shuffle(false).build().unwrap();
+    /// r_p.join();
+    /// let pwd = r_p.val();
+    /// assert!(pwd[..4].chars().all(|ch| ch.is_ascii_alphabetic()));
+    /// assert!(pwd[4..6].chars().all(|ch| ch.is_ascii_punctuation()));
+    /// assert!(pwd[6..].chars().all(|ch| ch.is_ascii_digit()));
+    /// assert!(r_p.matches_policy(pwd));
+    ///
+    /// let r_p = RandPwd::builder().letters(4).shuffle(false).no_repeats(true).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::ConstraintUnsatisfiable("shuffle"));
+    /// ```
+    #[inline]
+    pub fn shuffle(mut self, val: bool) -> Self {
+        self.keep_order = !val;
+        self
+    }
+
+
+    /// Generate with rayon or on the calling thread only, see `RandPwd::set_parallel`
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::builder().letters(16).parallel(false).build().unwrap();
+    /// assert!(!r_p.parallel());
+    /// ```
+    #[inline]
+    pub fn parallel(mut self, val: bool) -> Self {
+        self.sequential = !val;
+        self
+    }
+
+
+    /// Isolate the displayed password for right-to-left text, see `RandPwd::set_bidi_safe`
+    #[inline]
+    pub fn bidi_safe(mut self, val: bool) -> Self {
+        self.bidi_safe = val;
+        self
+    }
+
+
+    /// Seed the generator, see `RandPwd::set_seed`
+    #[inline]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+
+    /// Exclude the ambiguous characters, see `RandPwd::set_exclude_ambiguous`
+    #[inline]
+    pub fn exclude_ambiguous(mut self, val: bool) -> Self {
+        self.exclude_ambiguous = val;
+        self
+    }
+
+
+    /// Remove the characters of `chars` from every pool, see `RandPwd::exclude_chars`
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().symbols(20).exclude_chars("\"'`").build().unwrap();
+    /// r_p.join();
+    /// assert!(!r_p.val().contains(|ch| "\"'`".contains(ch)));
+    /// ```
+    #[inline]
+    pub fn exclude_chars(mut self, chars: &str) -> Self {
+        self.excluded = Some(chars.to_string());
+        self
+    }
+
+
+    /// Draw the characters from `charset`, see `RandPwd::with_charset`,
+    /// `with_letters`, `with_symbols` and `with_numbers` override its pools
+    #[inline]
+    pub fn with_charset(mut self, charset: Charset) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+
+    /// Draw the letters only from `letters`, see `RandPwd::with_letters`
+    #[inline]
+    pub fn with_letters(mut self, letters: &str) -> Self {
+        self.letters = Some(letters.to_string());
+        self
+    }
+
+
+    /// Draw the symbols only from `symbols`, see `RandPwd::with_symbols`
+    #[inline]
+    pub fn with_symbols(mut self, symbols: &str) -> Self {
+        self.symbols = Some(symbols.to_string());
+        self
+    }
+
+
+    /// Draw the numbers only from `numbers`, see `RandPwd::with_numbers`
+    #[inline]
+    pub fn with_numbers(mut self, numbers: &str) -> Self {
+        self.numbers = Some(numbers.to_string());
+        self
+    }
+
+
+    /// Keep only the characters `prefer` finds easy to type, see `RandPwd::typeability`
+    /// ```
+    /// use rand_pwd::{ RandPwd, TypePrefer };
+    /// let mut r_p = RandPwd::builder().letters(10).symbols(2).typeability(TypePrefer::HomeRow).build().unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().chars().all(|ch| "asdfghjkl;'ASDFGHJKL:\"".contains(ch)));
+    /// ```
+    #[inline]
+    pub fn typeability(mut self, prefer: TypePrefer) -> Self {
+        self.typeability = Some(prefer);
+        self
+    }
+
+
+    /// Measure `typeability` on `layout` instead of QWERTY
+    #[inline]
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+
+    /// Append the check characters of `kind` to every password, see `RandPwd::with_checksum`
+    #[inline]
+    pub fn with_checksum(mut self, kind: CheckKind) -> Self {
+        self.checksum = Some(kind);
+        self
+    }
+
+
+    /// Start every password with `prefix`, like `sk_` for a namespaced token
+    ///
+    /// It's put in front once the password is complete, its checksum included. It can have
+    /// any character, the pools don't apply to it, and it doesn't count toward the length
+    /// nor the entropy. Anyone may know it, so it adds nothing to the secret and only makes
+    /// the password longer, count on the random part alone.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().letters(16).prefix("sk_").suffix("·v1").build().unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().starts_with("sk_") && r_p.val().ends_with("·v1"));
+    /// assert_eq!(r_p.val().chars().count(), 22);
+    /// assert_eq!(r_p.length(), 16u8.into());
+    /// assert_eq!(r_p.entropy_bits(), RandPwd::new(16, 0, 0).unwrap().entropy_bits());
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// assert!(!r_p.matches_policy(&r_p.val()[3..]));
+    /// ```
+    #[inline]
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+
+    /// End every password with `suffix`, see `prefix`
+    #[inline]
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+
+    /// Return the shortest `length` which holds every count and requirement of the options as given,
+    /// a shorter one may still be built with a required category borrowing a slot, see `require_letter`
+    ///
+    /// The letters need `min_upper` and `min_lower`, the symbols and the numbers need their counts,
+    /// or the fewest of `max_symbols` and `max_numbers`, and their distinct ones, and every
+    /// required category needs one character. A count given by a ratio isn't known before
+    /// the length, so only its requirement counts. `no_edge_symbols` needs two letters
+    /// or numbers around the symbols. The prefix, the suffix and the check characters don't count.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let builder = RandPwd::builder().min_upper(2).symbols(3).require_number(true);
+    /// assert_eq!(builder.min_viable_length(), 6u8.into());
+    /// let r_p = builder.clone().length(6).build().unwrap();
+    /// assert_eq!((r_p.symbol_count(), r_p.number_count()), (&3u8.into(), &1u8.into()));
+    /// // The number takes the slot of a symbol
+    /// let r_p = builder.length(5).build().unwrap();
+    /// assert_eq!((r_p.symbol_count(), r_p.number_count()), (&2u8.into(), &1u8.into()));
+    ///
+    /// let builder = RandPwd::builder().require_letter(true).symbols(1).no_edge_symbols(true);
+    /// assert_eq!(builder.min_viable_length(), 3u8.into());
+    /// ```
+    #[inline]
+    pub fn min_viable_length(&self) -> BigUint {
+
+        let count = |cnt: &BigUint, ratio: Option<f64>, distinct: usize, required: bool| {
+            let cnt = if ratio.is_some() { BigUint::zero() } else { cnt.clone() };
+            cnt.max(BigUint::from(distinct)).max(BigUint::from(required as u8))
+        };
+
+        let ltr_cnt = (&self.min_upper + &self.min_lower).max(BigUint::from(self.require_letter as u8));
+        let sbl_cnt = count(&self.sbl_cnt, self.sbl_ratio, self.min_distinct_sbl, self.require_symbol);
+        let num_cnt = count(&self.num_cnt, self.num_ratio, self.min_distinct_num, self.require_number);
+        let edges = if self.no_edge_symbols && !sbl_cnt.is_zero() { BigUint::from(2u8) } else { BigUint::zero() };
+
+        (ltr_cnt + &num_cnt).max(edges) + sbl_cnt
+
+    }
+
+
+    /// Validate the options and return the configured `RandPwd`
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::builder().letters(8).numbers(-2).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::InvalidCount("num"));
+    ///
+    /// let r_p = RandPwd::builder().letters(4).min_upper(3).min_lower(3).build();
+    /// assert_eq!(r_p.unwrap_err(), PasswordError::LengthTooSmall { length: 4u8.into(), required: 6u8.into(), deficit: 2u8.into() });
+    /// ```
+    #[inline]
+    pub fn build(mut self) -> Result<RandPwd, PasswordError> {
+
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        // The counts set so far are the floors of the ranges
+        let sbl_floor = self.max_sbl.take().map(|max| mem::replace(&mut self.sbl_cnt, max));
+        let num_floor = self.max_num.take().map(|max| mem::replace(&mut self.num_cnt, max));
+
+        self.apply_length()?;
+
+        let needed = &self.min_upper + &self.min_lower;
+        if needed > self.ltr_cnt {
+            return Err(PasswordError::length_too_small(self.ltr_cnt, needed));
+        }
+
+        let [ltr_cnt, sbl_cnt, num_cnt] = self.required(needed)?;
+
+        let floor = |floor: Option<BigUint>, required: bool, cnt: &BigUint, name| match floor {
+            Some(floor) if floor > *cnt => Err(PasswordError::ConstraintUnsatisfiable(name)),
+            Some(floor) => Ok(Some(floor.max(BigUint::from(required as u8)))),
+            None        => Ok(None),
+        };
+        let sbl_floor = floor(sbl_floor, self.require_symbol, &sbl_cnt, "max_symbols")?;
+        let num_floor = floor(num_floor, self.require_number, &num_cnt, "max_numbers")?;
+
+        let mut r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt)?;
+        r_p.sbl_floor = sbl_floor;
+        r_p.num_floor = num_floor;
+        r_p.min_upper = self.min_upper;
+        r_p.min_lower = self.min_lower;
+        r_p.set_exclude_ambiguous(self.exclude_ambiguous);
+        r_p.set_leetify(self.leet);
+        if let Some(seed) = self.seed {
+            r_p.set_seed(seed);
+        }
+        if let Some(charset) = self.charset {
+            r_p.with_charset(charset)?;
+        }
+        if let Some(letters) = &self.letters {
+            r_p.with_letters(letters)?;
+        }
+        if let Some(symbols) = &self.symbols {
+            r_p.with_symbols(symbols)?;
+        }
+        if let Some(numbers) = &self.numbers {
+            r_p.with_numbers(numbers)?;
+        }
+        if let Some(prefer) = self.typeability {
+            r_p.typeability(prefer, &self.layout)?;
+        }
+        if let Some(excluded) = &self.excluded {
+            r_p.exclude_chars(excluded)?;
+        }
+        r_p.set_case(self.case)?;
+        if let Some(kind) = self.checksum {
+            r_p.with_checksum(kind);
+        }
+        r_p.prefix = self.prefix;
+        r_p.suffix = self.suffix;
+        if self.ascii_only && _POOLS(&r_p).iter().any(|(_, pool)| pool.iter().any(|ch| !ch.is_ascii())) {
+            return Err(PasswordError::ConstraintUnsatisfiable("ascii_only"));
+        }
+        // Even the fewest symbols or numbers a password can have must hold the distinct ones
+        let pools = _POOLS(&r_p);
+        for (i, floor, cnt, val, name) in [(3, &r_p.sbl_floor, &r_p.sbl_cnt, self.min_distinct_sbl, "min_distinct_symbols"),
+                                           (4, &r_p.num_floor, &r_p.num_cnt, self.min_distinct_num, "min_distinct_numbers")].iter() {
+            if *val > pools[*i].1.len() || BigUint::from(*val) > *floor.as_ref().unwrap_or(cnt) {
+                return Err(PasswordError::ConstraintUnsatisfiable(name));
+            }
+        }
+        if self.all_distinct && !_CAN_ALL_DISTINCT(&r_p) {
+            return Err(PasswordError::ConstraintUnsatisfiable("all_distinct"));
+        }
+        if self.no_repeats && !_CAN_NO_REPEATS(&r_p) {
+            return Err(PasswordError::ConstraintUnsatisfiable("no_repeats"));
+        }
+        // The letters and the numbers have to fill both edges, or the only one
+        let edges = (&r_p.ltr_cnt + &r_p.sbl_cnt + &r_p.num_cnt).min(BigUint::from(2u8));
+        if self.no_edge_symbols && !r_p.sbl_cnt.is_zero() && &r_p.ltr_cnt + &r_p.num_cnt < edges {
+            return Err(PasswordError::ConstraintUnsatisfiable("no_edge_symbols"));
+        }
+        if self.keep_order && (self.no_repeats || self.no_sequences || self.no_edge_symbols) {
+            return Err(PasswordError::ConstraintUnsatisfiable("shuffle"));
+        }
+        r_p.no_repeats = self.no_repeats;
+        r_p.no_sequences = self.no_sequences;
+        r_p.no_edge_symbols = self.no_edge_symbols;
+        r_p.min_distinct_sbl = self.min_distinct_sbl;
+        r_p.min_distinct_num = self.min_distinct_num;
+        r_p.all_distinct = self.all_distinct;
+        r_p.shuffle = !self.keep_order;
+        r_p.parallel = !self.sequential;
+        r_p.bidi_safe = self.bidi_safe;
+
+        Ok(r_p)
+
+    }
+
+
+    /// Turn `length` and the ratios into the counts, the letters take the remainder
+    #[inline]
+    fn apply_length(&mut self) -> Result<(), PasswordError> {
+
+        if self.length.is_none() && self.sbl_ratio.is_none() && self.num_ratio.is_none() {
+            return Ok(());
+        }
+
+        let total = self.length.take().unwrap_or_else(|| &self.ltr_cnt + &self.sbl_cnt + &self.num_cnt);
+        let part = |kind, ratio: f64| {
+            total.to_f64().and_then(|total| BigUint::from_f64(total * ratio + 0.5)).ok_or(PasswordError::InvalidCount(kind))
+        };
+
+        if let Some(ratio) = self.sbl_ratio {
+            self.sbl_cnt = part("sbl", ratio)?;
+        }
+        if let Some(ratio) = self.num_ratio {
+            self.num_cnt = part("num", ratio)?;
+        }
+
+        let needed = &self.sbl_cnt + &self.num_cnt;
+        if needed > total {
+            return Err(PasswordError::length_too_small(total, needed));
+        }
+        self.ltr_cnt = total - needed;
+
+        Ok(())
+
+    }
+
+
+    /// The counts of letters, symbols and numbers after every required category
+    /// with a zero count borrowed a slot, the letters keep at least `ltr_floor`
+    #[inline]
+    fn required(&self, ltr_floor: BigUint) -> Result<[BigUint; 3], PasswordError> {
+
+        let required = [self.require_letter, self.require_symbol, self.require_number];
+        let floors = [
+            ltr_floor.max(BigUint::from(self.require_letter as u8)),
+            BigUint::from(self.require_symbol as u8),
+            BigUint::from(self.require_number as u8),
+        ];
+        let mut cnts = [self.ltr_cnt.clone(), self.sbl_cnt.clone(), self.num_cnt.clone()];
+
+        for i in 0..3 {
+            if required[i] && cnts[i].is_zero() {
+                match (0..3).find(|&j| j != i && cnts[j] > floors[j]) {
+                    Some(j) => {
+                        cnts[j] -= BigUint::one();
+                        cnts[i] += BigUint::one();
+                    }
+                    None => return Err(PasswordError::length_too_small(cnts.iter().sum(), floors.iter().sum())),
+                }
+            }
+        }
+
+        Ok(cnts)
+
+    }
+
+
+    /// Check `val` is in `0.0..=1.0`, remember the first failure for `build`
+    #[inline]
+    fn ratio(&mut self, kind: &'static str, val: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&val) {
+            self.error.get_or_insert(PasswordError::InvalidCount(kind));
+        }
+        Some(val)
+    }
+
+
+    /// Convert `val` into `BigUint`, remember the first failure for `build`
+    #[inline]
+    fn count<T: ToBigUint>(&mut self, kind: &'static str, val: T) -> BigUint {
+        val.to_biguint().unwrap_or_else(|| {
+            self.error.get_or_insert(PasswordError::InvalidCount(kind));
+            BigUint::zero()
+        })
+    }
+
+}
@@ -0,0 +1,32 @@
+use alloc::string::String;
+use crate::Strength;
+
+
+/// A password yielded by `RandPwd::iter_detailed` together with its entropy
+///
+/// The entropy comes from the configuration, so it's the same for every password of an iterator,
+/// it's bundled to spare recomputing it where the passwords are filtered.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate {
+    /// The generated password
+    pub password: String,
+    /// What `RandPwd::entropy_bits` returned for the configuration
+    pub entropy_bits: f64,
+}
+
+
+impl Candidate {
+
+    /// Classify the entropy of the password, see `Strength::from_bits`
+    /// ```
+    /// use rand_pwd::{ Candidate, Strength };
+    /// let candidate = Candidate { password: "hunter2".to_string(), entropy_bits: 41.0 };
+    /// assert_eq!(candidate.strength(), Strength::Medium);
+    /// ```
+    #[inline]
+    pub fn strength(&self) -> Strength {
+        Strength::from_bits(self.entropy_bits)
+    }
+
+}
@@ -0,0 +1,63 @@
+/// How the case of the letters is normalized after the password is generated
+///
+/// `Upper` and `Lower` collapse the pool of letters into one case, so the entropy
+/// is the one of the smaller alphabet, e.g. the 52 ASCII letters count as 26.
+/// ```
+/// use rand_pwd::{ RandPwd, CasePolicy };
+/// let mut r_p = RandPwd::new(16, 0, 0).unwrap();
+/// r_p.set_case(CasePolicy::Upper).unwrap();
+/// r_p.join();
+/// assert!(r_p.val().chars().all(|ch| ch.is_ascii_uppercase()));
+/// assert_eq!(r_p.alphabet_size(), 26);
+///
+/// r_p.set_case(CasePolicy::Alternating).unwrap();
+/// r_p.join();
+/// assert!(r_p.val().chars().step_by(2).all(|ch| ch.is_ascii_lowercase()));
+/// assert!(r_p.val().chars().skip(1).step_by(2).all(|ch| ch.is_ascii_uppercase()));
+/// assert!(r_p.matches_policy(r_p.val()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CasePolicy {
+    /// Keep the letters as they are drawn
+    #[default]
+    AsIs,
+    /// Make every letter uppercase
+    Upper,
+    /// Make every letter lowercase
+    Lower,
+    /// Make the letters at even positions lowercase and the ones at odd positions uppercase
+    Alternating,
+}
+
+
+impl CasePolicy {
+
+    /// Return `ch` in the case for the position `i`,
+    /// a character whose other case takes several characters is kept
+    #[inline]
+    pub(crate) fn map(self, ch: char, i: usize) -> char {
+
+        let upper = match self {
+            CasePolicy::AsIs        => return ch,
+            CasePolicy::Upper       => true,
+            CasePolicy::Lower       => false,
+            CasePolicy::Alternating => i % 2 == 1,
+        };
+        let (mut to_upper, mut to_lower);
+        let cased: &mut dyn Iterator<Item = char> = if upper {
+            to_upper = ch.to_uppercase();
+            &mut to_upper
+        } else {
+            to_lower = ch.to_lowercase();
+            &mut to_lower
+        };
+
+        match (cased.next(), cased.next()) {
+            (Some(single), None) => single,
+            _ => ch,
+        }
+
+    }
+
+}
@@ -0,0 +1,198 @@
+use crate::{ RandPwd, PasswordError, CasePolicy };
+use crate::prelude::*;
+
+
+/// The pools of letters, symbols and numbers to draw the characters from
+///
+/// A pool that isn't given falls back to the ASCII letters, punctuations or digits,
+/// those are the only ones `exclude_ambiguous` filters.
+///
+/// A character is drawn from one pool only, so it can't skew the distribution:
+/// if it's in several pools it's kept in the first of letters, symbols and numbers
+/// whose count is non-zero,
+/// and it's kept once in a pool that has it more than once. `RandPwd::exclude_chars`
+/// applies to every pool, and the draws are uniform unless `with_weights` says otherwise.
+/// # Example
+/// ```
+/// use rand_pwd::{ RandPwd, Charset };
+/// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+/// r_p.with_charset(Charset::custom("abcdef", "-_", "0123456789")).unwrap();
+/// r_p.join();
+/// assert!(r_p.val().chars().all(|ch| "abcdef-_0123456789".contains(ch)));
+///
+/// // `0123` are symbols now, the numbers are left with `4` to `9`
+/// let mut r_p = RandPwd::new(0, 4, 4).unwrap();
+/// r_p.with_symbols("0123").unwrap();
+/// r_p.join();
+/// assert_eq!(r_p.val().chars().filter(|ch| "456789".contains(*ch)).count(), 4);
+/// assert_eq!(r_p.alphabet_size(), 10);
+/// assert!((r_p.entropy_bits() - (4.0 * 4f64.log2() + 4.0 * 6f64.log2())).abs() < 1e-9);
+///
+/// // Nothing is left for the numbers
+/// assert!(r_p.with_symbols("0123456789").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Charset {
+    pub(crate) letters: Option<Vec<char>>,
+    pub(crate) symbols: Option<Vec<char>>,
+    pub(crate) numbers: Option<Vec<char>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) weights: BTreeMap<char, f64>,
+}
+
+
+impl Charset {
+
+    /// Return a `Charset` made of exactly the characters of `letters`, `symbols` and `numbers`
+    #[inline]
+    pub fn custom(letters: &str, symbols: &str, numbers: &str) -> Self {
+        Charset {
+            letters: Some(_CHARS(letters)),
+            symbols: Some(_CHARS(symbols)),
+            numbers: Some(_CHARS(numbers)),
+            weights: BTreeMap::new(),
+        }
+    }
+
+
+    /// Return a `Charset` made of the characters of the inclusive code point ranges
+    /// of `letters`, `symbols` and `numbers`, the code points which aren't characters are skipped
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset };
+    /// // CJK ideographs, box drawing and the fullwidth digits
+    /// let charset = Charset::from_ranges(&[(0x4E00, 0x9FFF)], &[(0x2500, 0x257F)], &[(0xFF10, 0xFF19)]);
+    /// let mut r_p = RandPwd::new(20, 4, 4).unwrap();
+    /// r_p.with_charset(charset).unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.val().chars().count(), 28);
+    /// assert_eq!(r_p.len(), 28 * 3);
+    /// assert_eq!(r_p.val().chars().filter(|ch| ('\u{4E00}'..='\u{9FFF}').contains(ch)).count(), 20);
+    /// assert_eq!(r_p.alphabet_size(), 0x5200 + 0x80 + 10);
+    ///
+    /// // The surrogates are left out
+    /// let mut r_p = RandPwd::new(0, 0, 4).unwrap();
+    /// r_p.with_charset(Charset::from_ranges(&[], &[], &[(0xD7FE, 0xE001)])).unwrap();
+    /// assert_eq!(r_p.alphabet_size(), 4);
+    /// ```
+    #[inline]
+    pub fn from_ranges(letters: &[(u32, u32)], symbols: &[(u32, u32)], numbers: &[(u32, u32)]) -> Self {
+        Charset {
+            letters: Some(_RANGES(letters)),
+            symbols: Some(_RANGES(symbols)),
+            numbers: Some(_RANGES(numbers)),
+            weights: BTreeMap::new(),
+        }
+    }
+
+
+    /// Draw the characters of `weights` more or less often than the others of their pool,
+    /// a character which isn't given weighs `1.0` and one weighing `0.0` is never drawn
+    ///
+    /// `entropy_bits`, `combinations` and `alphabet_size` still count every character
+    /// which can be drawn as equally likely, so they overrate a skewed pool.
+    /// The consonants and vowels of pronounceable passwords aren't weighted.
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset, PasswordError };
+    /// let charset = Charset::custom("ab", "-_", "0").with_weights(&[('a', 3.0), ('_', 0.0)]).unwrap();
+    /// let mut r_p = RandPwd::new(4000, 100, 0).unwrap();
+    /// r_p.with_charset(charset).unwrap();
+    /// r_p.join();
+    /// // 3 in 4 letters are `a`
+    /// assert!((2800..3200).contains(&r_p.val().matches('a').count()));
+    /// assert_eq!(r_p.val().matches('-').count(), 100);
+    ///
+    /// assert_eq!(Charset::default().with_weights(&[('a', -1.0)]), Err(PasswordError::InvalidCount("weight")));
+    /// ```
+    #[inline]
+    pub fn with_weights(mut self, weights: &[(char, f64)]) -> Result<Self, PasswordError> {
+        for &(ch, weight) in weights {
+            if !(weight.is_finite() && weight >= 0.0) {
+                return Err(PasswordError::InvalidCount("weight"));
+            }
+            self.weights.insert(ch, weight);
+        }
+        Ok(self)
+    }
+
+
+    /// The weight of `ch` in its pool, see `with_weights`
+    #[inline]
+    pub(crate) fn weight(&self, ch: char) -> f64 {
+        self.weights.get(&ch).copied().unwrap_or(1.0)
+    }
+
+
+    /// The count and the pool of every category of `r_p` drawn from this `Charset`, in the order like
+    /// "letters->uppercase letters->lowercase letters->symbols->numbers", without the duplicates
+    /// and with the letters collapsed by the `CasePolicy` of `r_p`
+    #[inline]
+    pub(crate) fn pools(&self, r_p: &RandPwd) -> Vec<(BigUint, Vec<char>)> {
+
+        let free_ltr = &r_p.ltr_cnt - &r_p.min_upper - &r_p.min_lower;
+        let counts = vec![free_ltr, r_p.min_upper.clone(), r_p.min_lower.clone(), r_p.sbl_cnt.clone(), r_p.num_cnt.clone()];
+
+        // Nothing to filter out of the default pools, they're built only once
+        if *self == Charset::default() && r_p.excluded.is_empty() && r_p.case == CasePolicy::AsIs {
+            let pools: &PoolVec = if r_p.exclude_ambiguous { &POOLS_UNAMBIGUOUS } else { &POOLS };
+            return counts.into_iter().zip(pools.iter().cloned()).collect();
+        }
+
+        let data: &CharVec = if r_p.exclude_ambiguous { &DATA_UNAMBIGUOUS } else { &DATA };
+        let letters: &[char] = self.letters.as_deref().unwrap_or(&data[0]);
+        let symbols: &[char] = self.symbols.as_deref().unwrap_or(&data[1]);
+        let numbers: &[char] = self.numbers.as_deref().unwrap_or(&data[2]);
+
+        let mut seen = BTreeSet::new();
+        let mut dedup = |pool: &[char]| {
+            pool.iter()
+                .copied()
+                .filter(|ch| !r_p.excluded.contains(*ch))
+                .filter(|ch| self.weight(*ch) > 0.0)
+                .filter(|ch| seen.insert(*ch))
+                .collect::<Vec<_>>()
+        };
+        // The categories which are drawn from have the first pick, so an unused pool
+        // of letters doesn't take `a` to `f` from hexadecimal numbers
+        let pools = [letters, symbols, numbers];
+        let drawn = [!r_p.ltr_cnt.is_zero(), !r_p.sbl_cnt.is_zero(), !r_p.num_cnt.is_zero()];
+        let mut deduped = [Vec::new(), Vec::new(), Vec::new()];
+        for i in (0..3).filter(|i| drawn[*i]).chain((0..3).filter(|i| !drawn[*i])) {
+            deduped[i] = dedup(pools[i]);
+        }
+        let [letters, symbols, numbers] = deduped;
+
+        // Only one letter of every pair like `a` and `A` is left when the case is normalized,
+        // the one already in the right case if there's one
+        let cased = |ch: char| r_p.case.map(ch, 0);
+        let letters = match r_p.case {
+            CasePolicy::AsIs => letters,
+            _ => letters
+                .iter()
+                .copied()
+                .filter(|ch| cased(*ch) == *ch || !letters.contains(&cased(*ch)))
+                .collect(),
+        };
+
+        let uppers = letters.iter().copied().filter(|ch| cased(*ch).is_uppercase()).collect();
+        let lowers = letters.iter().copied().filter(|ch| cased(*ch).is_lowercase()).collect();
+
+        counts.into_iter().zip(vec![letters, uppers, lowers, symbols, numbers]).collect()
+
+    }
+
+
+    /// Check every category of `r_p` with a non-zero count has something to draw from
+    #[inline]
+    pub(crate) fn validate(&self, r_p: &RandPwd) -> Result<(), PasswordError> {
+
+        let kinds = ["ltr", "upper", "lower", "sbl", "num"];
+
+        match self.pools(r_p).iter().position(|(cnt, pool)| !cnt.is_zero() && pool.is_empty()) {
+            Some(i) => Err(PasswordError::EmptyPool(kinds[i])),
+            None    => Ok(()),
+        }
+
+    }
+
+}
@@ -0,0 +1,98 @@
+use alloc::{ vec, vec::Vec, string::String, collections::BTreeSet };
+use crate::{ RandPwd, Mode };
+use num_traits::Zero;
+use crate::prelude::_POOL_SETS;
+
+
+/// The check characters `RandPwd::with_checksum` appends to the passwords,
+/// they catch the mistakes of a password typed by hand
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckKind {
+    /// One character by the Luhn mod N algorithm over the sorted characters of the pools drawn from,
+    /// or of the groups of `RandPwd::from_groups`,
+    /// which catches any single mistyped character and most swaps of neighbors.
+    /// Over the digits alone it's the usual Luhn check digit.
+    LuhnModN,
+    /// The CRC-8 of the UTF-8 bytes, polynomial `0x07`, as two uppercase hexadecimal digits
+    Crc8,
+}
+
+
+impl CheckKind {
+
+    /// The count of characters appended
+    #[inline]
+    pub(crate) fn len(self) -> usize {
+        match self {
+            CheckKind::LuhnModN => 1,
+            CheckKind::Crc8     => 2,
+        }
+    }
+
+
+    /// The check characters of `body`, none if the pools of `r_p` are all empty
+    ///
+    /// The Luhn sum skips the characters in none of the pools, like a separator or a leet substitute.
+    #[inline]
+    pub(crate) fn compute(self, r_p: &RandPwd, body: &[char]) -> Option<Vec<char>> {
+
+        const HEX: &[u8] = b"0123456789ABCDEF";
+
+        match self {
+            CheckKind::LuhnModN => {
+                // The words of a passphrase are made of letters
+                let letters = !r_p.ltr_cnt.is_zero() || matches!(r_p.mode, Mode::Passphrase { .. });
+                let drawn = [letters, letters, letters, !r_p.sbl_cnt.is_zero(), !r_p.num_cnt.is_zero()];
+                let sets = match r_p.mode {
+                    Mode::Groups => r_p.groups.iter().filter(|(_, cnt)| *cnt > 0).map(|(alphabet, _)| alphabet.chars().collect()).collect(),
+                    _ => _POOL_SETS(r_p).into_iter().zip(drawn.iter()).filter(|(_, drawn)| **drawn).map(|(set, _)| set).collect::<Vec<_>>(),
+                };
+                let alphabet = sets
+                    .into_iter()
+                    .flatten()
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let n = alphabet.len();
+                if n == 0 { return None; }
+
+                // Every other code from the right is doubled, the digits of the result in base `n` add up
+                let sum = body
+                    .iter()
+                    .rev()
+                    .filter_map(|ch| alphabet.binary_search(ch).ok())
+                    .enumerate()
+                    .map(|(i, code)| if i % 2 == 0 { code * 2 / n + code * 2 % n } else { code })
+                    .sum::<usize>();
+
+                Some(vec![alphabet[(n - sum % n) % n]])
+            }
+            CheckKind::Crc8 => {
+                let crc = body.iter().collect::<String>().bytes().fold(0u8, |crc, byte| {
+                    (0..8).fold(crc ^ byte, |crc, _| if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 })
+                });
+
+                Some(vec![HEX[(crc >> 4) as usize] as char, HEX[(crc & 0xF) as usize] as char])
+            }
+        }
+
+    }
+
+
+    /// Split the check characters off `pwd` and return the rest, none if they don't match it
+    #[inline]
+    pub(crate) fn strip<'a>(self, r_p: &RandPwd, pwd: &'a str) -> Option<&'a str> {
+
+        let (split, _) = pwd.char_indices().rev().nth(self.len() - 1)?;
+        let (body, check) = pwd.split_at(split);
+
+        if self.compute(r_p, &body.chars().collect::<Vec<_>>())?.into_iter().eq(check.chars()) {
+            Some(body)
+        } else {
+            None
+        }
+
+    }
+
+}
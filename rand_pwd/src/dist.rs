@@ -0,0 +1,59 @@
+use alloc::string::String;
+use rand::{ Rng, distributions::Distribution };
+use crate::RandPwd;
+use crate::prelude::_JOIN;
+
+
+/// The passwords of a `RandPwd` as a distribution, drawn from the generator given to `sample`
+///
+/// Only the configuration is kept, the seed of `RandPwd` isn't used, the generator decides.
+/// # Panics
+/// `sample` panics like `RandPwd::join` if the constraints can't be satisfied.
+/// # Example
+/// ```
+/// use rand_pwd::{ RandPwd, PasswordDist };
+/// use rand::{ Rng, SeedableRng, rngs::StdRng };
+/// let dist = PasswordDist::new(&RandPwd::new(10, 2, 3).unwrap());
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let pwd: String = rng.sample(&dist);
+/// assert_eq!(pwd.len(), 15);
+///
+/// let pwds = StdRng::seed_from_u64(7).sample_iter(&dist).take(3).collect::<Vec<_>>();
+/// assert_eq!(pwds[0], pwd);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PasswordDist {
+    r_p: RandPwd,
+}
+
+
+impl PasswordDist {
+
+    /// Return a distribution of the passwords `r_p` generates, its content isn't copied
+    #[inline]
+    pub fn new(r_p: &RandPwd) -> Self {
+        PasswordDist { r_p: r_p.clone_config() }
+    }
+
+}
+
+
+impl From<RandPwd> for PasswordDist {
+
+    #[inline]
+    fn from(r_p: RandPwd) -> Self {
+        PasswordDist::new(&r_p)
+    }
+
+}
+
+
+impl Distribution<String> for PasswordDist {
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        _JOIN(&self.r_p, &mut &mut *rng).unwrap()
+    }
+
+}
@@ -0,0 +1,74 @@
+use core::fmt;
+use alloc::string::String;
+use num_bigint::BigUint;
+
+
+/// Errors returned when building or generating a `RandPwd`
+#[derive(Clone, Debug, PartialEq)]
+pub enum PasswordError {
+    /// The count of `"ltr"`, `"sbl"` or `"num"`, or the `"len"`, can't be represented as
+    /// an unsigned integer, e.g. it's negative, or the ratio of `"sbl"` or `"num"` isn't in `0.0..=1.0`,
+    /// or the size of a `"group"` is zero or its count doesn't fit a `usize`, or a `"weight"` is negative or not finite
+    InvalidCount(&'static str),
+    /// The characters pool of `"ltr"`, `"upper"`, `"lower"`, `"sbl"` or `"num"`,
+    /// or the list of `"word"`s is empty while its count is non-zero
+    EmptyPool(&'static str),
+    /// The guaranteed characters need `required` slots but only `length` are there,
+    /// `deficit` short
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let err = RandPwd::builder().length(5).symbols(4).numbers(3).build().unwrap_err();
+    /// assert_eq!(err.to_string(), "length 5 is 2 short of the required 7");
+    /// ```
+    LengthTooSmall { length: BigUint, required: BigUint, deficit: BigUint },
+    /// The named constraint can't be satisfied with the configured pools and counts
+    ConstraintUnsatisfiable(&'static str),
+    /// The string isn't a configuration like `"ltr:sbl:num"`
+    Parse(String),
+    /// The clause of a policy like `"len>=12;digits>=2"` isn't supported
+    Policy(String),
+    /// The character of a pattern like `"LLDS"` isn't `L`, `S` or `D`
+    Pattern(char),
+    /// The clipboard can't be opened or written, with the reason given by `arboard`
+    Clipboard(String),
+    /// The range request to Have I Been Pwned failed, with the reason
+    Pwned(String),
+}
+
+
+impl fmt::Display for PasswordError {
+
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PasswordError::InvalidCount(kind) => write!(f, "the count of {} must be a non-negative integer", kind),
+            PasswordError::EmptyPool(kind)    => write!(f, "no characters to choose from for {}", kind),
+            PasswordError::LengthTooSmall { length, required, deficit } => {
+                write!(f, "length {} is {} short of the required {}", length, deficit, required)
+            }
+            PasswordError::ConstraintUnsatisfiable(name) => write!(f, "the constraint {} can't be satisfied", name),
+            PasswordError::Parse(s) => write!(f, "invalid configuration {:?}, expected \"ltr:sbl:num\"", s),
+            PasswordError::Policy(clause) => write!(f, "unsupported policy clause {:?}", clause),
+            PasswordError::Pattern(ch) => write!(f, "invalid pattern character {:?}, expected L, S or D", ch),
+            PasswordError::Clipboard(reason) => write!(f, "can't copy to the clipboard, {}", reason),
+            PasswordError::Pwned(reason) => write!(f, "can't query Have I Been Pwned, {}", reason),
+        }
+    }
+
+}
+
+
+impl PasswordError {
+
+    /// Return `LengthTooSmall` for `length` slots which can't hold `required` characters
+    #[inline]
+    pub(crate) fn length_too_small(length: BigUint, required: BigUint) -> Self {
+        let deficit = &required - &length;
+        PasswordError::LengthTooSmall { length, required, deficit }
+    }
+
+}
+
+
+#[cfg(feature = "std")]
+impl std::error::Error for PasswordError {}
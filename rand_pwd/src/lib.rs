@@ -1,32 +1,159 @@
 #![allow(non_snake_case)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate lazy_static;
+extern crate alloc;
 
+
+/// Run `$body` in the `tracing` span `$name`, it's just `$body` without the `tracing` feature
+macro_rules! _SPAN {
+    ($name:literal, $body:expr) => {{
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($name).entered();
+        $body
+    }};
+}
+
+mod error;
+mod builder;
+mod charset;
+mod strength;
+mod case;
+mod candidate;
+mod checksum;
+mod dist;
+mod policy;
+mod wordlist;
+mod vectors;
+mod typeability;
 mod prelude;
+#[cfg(feature = "serde")]
+mod biguint_str;
+#[cfg(feature = "wasm")]
+mod wasm;
 use prelude::*;
 
+pub use error::PasswordError;
+pub use builder::RandPwdBuilder;
+pub use charset::Charset;
+pub use strength::{ Strength, EntropyReport };
+pub use case::CasePolicy;
+pub use candidate::Candidate;
+pub use checksum::CheckKind;
+pub use dist::PasswordDist;
+pub use typeability::{ TypePrefer, Layout };
+#[cfg(feature = "wasm")]
+pub use wasm::WasmPwd;
+
 
 /// struct `RandPwd`
+///
+/// Without the default `std` feature the crate is `no_std` and needs only `alloc`,
+/// the passwords are generated with `join_with_rng` from a generator supplied by the caller,
+/// everything that relies on `thread_rng`, `rayon`, `std::io` or floating point math is left out.
+///
+/// With the `zeroize` feature the password is wiped when `RandPwd` is dropped,
+/// but the copies made by `clone`, `to_string` or the likes of `val().to_owned()`
+/// are out of its reach and must be wiped by yourself.
+///
+/// With the `tracing` feature the steps of the generation run in `tracing` spans at the info level,
+/// `"div_unit"`, the split of a pool count into chunks, `"chunk"`, the draw of one chunk,
+/// which runs on the threads of rayon, and `"shuffle"`. A subscriber of your choice collects them.
+///
+/// With the `wasm` feature `WasmPwd` exports `RandPwd` to JavaScript through `wasm-bindgen`.
+///
+/// With the `serde` feature the configuration can be (de)serialized, the password is skipped
+/// and the counts are written as decimal strings. A deserialized configuration is not validated again.
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use rand_pwd::RandPwd;
+/// let ltr_cnt = format!("{}000", u128::MAX);
+/// let mut r_p = RandPwd::new(ltr_cnt.parse::<num_bigint::BigUint>().unwrap(), 2, 3).unwrap();
+/// r_p.set_val("secret");
+///
+/// let json = serde_json::to_string(&r_p).unwrap();
+/// assert!(json.contains(&format!("\"{}\"", ltr_cnt)));
+/// assert!(!json.contains("secret"));
+///
+/// let r_p: RandPwd = serde_json::from_str(&json).unwrap();
+/// assert_eq!(r_p.get_cnt("ltr").unwrap().to_string(), ltr_cnt);
+/// assert!(r_p.is_empty());
+/// # }
+/// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RandPwd {
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str"))]
     ltr_cnt: BigUint,
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str"))]
     sbl_cnt: BigUint,
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str"))]
     num_cnt: BigUint,
+    // The counts of symbols and numbers are drawn from `floor..=cnt` if there's a floor
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str::option"))]
+    sbl_floor: Option<BigUint>,
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str::option"))]
+    num_floor: Option<BigUint>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     content: String, // TODO: - use the heapless String
     _UNIT: usize,    // TODO: - implement a smart _UNIT initialization to get best performance
+    seed: Option<u64>,
+    exclude_ambiguous: bool,
+    excluded: String,
+    charset: Charset,
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str"))]
+    min_upper: BigUint,
+    #[cfg_attr(feature = "serde", serde(with = "biguint_str"))]
+    min_lower: BigUint,
+    no_repeats: bool,
+    no_sequences: bool,
+    no_edge_symbols: bool,
+    min_distinct_sbl: usize,
+    min_distinct_num: usize,
+    all_distinct: bool,
+    shuffle: bool,
+    parallel: bool,
+    bidi_safe: bool,
+    case: CasePolicy,
+    mode: Mode,
+    wordlist: Option<Vec<String>>,
+    leet: f64,
+    // The category of every position, `L`, `S` or `D`
+    pattern: Option<String>,
+    checksum: Option<CheckKind>,
+    prefix: String,
+    suffix: String,
+    // The alphabets of `from_groups` without duplicates and their counts
+    groups: Vec<(String, usize)>,
+}
+
+
+/// What `RandPwd` generates
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Mode {
+    /// Letters, symbols and numbers
+    Chars,
+    /// Words joined by the separator
+    Passphrase { word_cnt: usize, separator: char },
+    /// Alternating consonants and vowels with the symbols and numbers sprinkled in
+    Pronounceable,
+    /// Every group drawn from its own alphabet, shuffled together
+    Groups,
 }
 
 
 impl RandPwd {
 
-    /// Return an empty instance of `Result<RandPwd, &'static str>`
+    /// Return an empty instance of `Result<RandPwd, PasswordError>`
     /// # Example
     /// ```
-    /// use rpg::RandPwd;
+    /// use rand_pwd::{ RandPwd, PasswordError };
     /// use num_bigint::BigUint;
-    /// let mut r_p = RandPwd::new(11, 4, 2);
+    /// let mut r_p = RandPwd::new(11, 4, 2).unwrap();
     ///
     /// // If you want push a large number in it
     /// // parse the `&str` into `BigUint`
@@ -36,25 +163,377 @@ impl RandPwd {
     /// let sbl_cnt = BigUint::from_str(&format!("{}000", usize::MAX)).unwrap();
     /// let num_cnt = BigUint::from_str(&format!("{}000", usize::MAX)).unwrap();
     ///
-    /// r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt);
+    /// r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt).unwrap();
     ///
     /// // You can also mix the `BigUint` with primitive type
+    ///
+    /// // A negative count is rejected
+    /// assert_eq!(RandPwd::new(-1, 4, 2).unwrap_err(), PasswordError::InvalidCount("ltr"));
+    /// ```
+    #[inline]
+    pub fn new<L, S, N>(ltr_cnt: L, sbl_cnt: S, num_cnt: N) -> Result<Self, PasswordError>
+    where L: ToBigUint,
+          S: ToBigUint,
+          N: ToBigUint,
+    {
+
+        Ok(RandPwd::from_counts(
+            ltr_cnt.to_biguint().ok_or(PasswordError::InvalidCount("ltr"))?,
+            sbl_cnt.to_biguint().ok_or(PasswordError::InvalidCount("sbl"))?,
+            num_cnt.to_biguint().ok_or(PasswordError::InvalidCount("num"))?,
+        ))
+
+    }
+
+
+    /// Return an instance of `RandPwd` from the counts of letters, symbols and numbers
+    /// in a tuple, like `new`
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let configs = vec![(10, 2, 3), (20, 0, 4)];
+    /// let r_ps = configs.into_iter().map(RandPwd::from_tuple).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(r_ps[1].get_cnt("num").unwrap(), &4u8.into());
     /// ```
     #[inline]
-    pub fn new<L, S, N>(ltr_cnt: L, sbl_cnt: S, num_cnt: N) -> Self
+    pub fn from_tuple<L, S, N>((ltr_cnt, sbl_cnt, num_cnt): (L, S, N)) -> Result<Self, PasswordError>
     where L: ToBigUint,
           S: ToBigUint,
           N: ToBigUint,
     {
+        RandPwd::new(ltr_cnt, sbl_cnt, num_cnt)
+    }
+
+
+    /// Return an instance of `RandPwd` of `length` characters, `sbl_cnt` symbols
+    /// and `num_cnt` numbers, the letters take the rest
+    ///
+    /// A `usize` always converts, so nothing is checked, which spares the `Result` of `new`
+    /// on hot paths whose inputs are already validated. It may panic or misbehave
+    /// if `length < sbl_cnt + num_cnt`.
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// use num_traits::ToPrimitive;
+    /// let mut r_p = RandPwd::new_unchecked(16, 4, 2);
+    /// r_p.join();
+    /// assert_eq!(r_p.val().len(), 16);
+    /// assert_eq!(r_p.get_cnt("ltr").unwrap().to_usize().unwrap(), 10);
+    /// ```
+    #[inline]
+    pub fn new_unchecked(length: usize, sbl_cnt: usize, num_cnt: usize) -> Self {
+        RandPwd::from_counts(
+            BigUint::from(length - sbl_cnt - num_cnt),
+            BigUint::from(sbl_cnt),
+            BigUint::from(num_cnt),
+        )
+    }
+
+
+    /// Build the instance with the defaults around the counts
+    #[inline]
+    fn from_counts(ltr_cnt: BigUint, sbl_cnt: BigUint, num_cnt: BigUint) -> Self {
 
         RandPwd {
-            ltr_cnt: ltr_cnt.to_biguint().unwrap(),
-            sbl_cnt: sbl_cnt.to_biguint().unwrap(),
-            num_cnt: num_cnt.to_biguint().unwrap(),
+            ltr_cnt,
+            sbl_cnt,
+            num_cnt,
+            sbl_floor: None,
+            num_floor: None,
             content: String::new(),
-            _UNIT: 1
+            _UNIT: DEFAULT_UNIT,
+            seed: None,
+            exclude_ambiguous: false,
+            excluded: String::new(),
+            charset: Charset::default(),
+            min_upper: BigUint::zero(),
+            min_lower: BigUint::zero(),
+            no_repeats: false,
+            no_sequences: false,
+            no_edge_symbols: false,
+            min_distinct_sbl: 0,
+            min_distinct_num: 0,
+            all_distinct: false,
+            shuffle: true,
+            parallel: true,
+            bidi_safe: false,
+            case: CasePolicy::AsIs,
+            mode: Mode::Chars,
+            wordlist: None,
+            leet: 0.0,
+            pattern: None,
+            checksum: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            groups: Vec::new(),
+        }
+
+    }
+
+
+    /// Return an instance of `RandPwd` which generates passphrases,
+    /// `word_cnt` words joined by `separator`
+    ///
+    /// The words are drawn from an embedded list of 256 words unless `with_wordlist` is used.
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::passphrase(5, '-');
+    /// r_p.join();
+    /// assert_eq!(r_p.val().split('-').count(), 5);
+    /// assert_eq!(r_p.entropy_bits(), 40.0);
+    /// ```
+    #[inline]
+    pub fn passphrase(word_cnt: usize, separator: char) -> Self {
+
+        let mut r_p = RandPwd::new(0, 0, 0).unwrap();
+        r_p.mode = Mode::Passphrase { word_cnt, separator };
+
+        r_p
+
+    }
+
+
+    /// Return an instance of `RandPwd` which generates a PIN of `digits` numbers
+    ///
+    /// The digits come from the pool of numbers, so `exclude_ambiguous` and a custom `Charset`
+    /// still apply.
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::pin(6);
+    /// r_p.set_exclude_ambiguous(true);
+    /// r_p.join();
+    /// assert_eq!(r_p.len(), 6);
+    /// assert!(r_p.val().chars().all(|ch| "2345679".contains(ch)));
+    /// ```
+    #[inline]
+    pub fn pin(digits: usize) -> Self {
+        RandPwd::new(0, 0, digits).unwrap()
+    }
+
+
+    /// Return an instance of `RandPwd` which generates `length` lowercase letters
+    /// alternating between consonants and vowels, like `bofeki`
+    ///
+    /// The symbols and numbers set by `set_cnt` are put at random positions among the letters.
+    /// The consonants and vowels stand on their own, the letters of a custom `Charset`,
+    /// `min_upper`, `min_lower`, `no_repeats`, `no_sequences` and `no_edge_symbols` don't apply.
+    ///
+    /// A letter is drawn from 18 consonants or 5 vowels instead of the 52 letters,
+    /// so the password is far more guessable than a random one of the same length,
+    /// see `entropy_bits`.
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::pronounceable(8);
+    /// r_p.set_cnt("num", 2).unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.category_counts(), (8, 0, 2));
+    ///
+    /// let vowels = r_p.val().chars().filter(|ch| ch.is_ascii_alphabetic()).map(|ch| "aeiou".contains(ch)).collect::<Vec<_>>();
+    /// assert!(vowels.windows(2).all(|w| w[0] != w[1]));
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// assert!(r_p.entropy_bits() < RandPwd::new(8, 0, 2).unwrap().entropy_bits());
+    /// ```
+    #[inline]
+    pub fn pronounceable(length: usize) -> Self {
+
+        let mut r_p = RandPwd::new(length, 0, 0).unwrap();
+        r_p.mode = Mode::Pronounceable;
+
+        r_p
+
+    }
+
+
+    /// Return an instance of `RandPwd` which draws `length` characters from `alphabet`,
+    /// whatever their category
+    ///
+    /// The characters are all letters for `RandPwd`, the counts of symbols and numbers are zero,
+    /// so they are drawn straight from `alphabet` without the categories nor the shuffle.
+    /// # Example
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::from_alphabet("0123456789abcdef", 32).unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.len(), 32);
+    /// assert!(r_p.val().chars().all(|ch| ch.is_ascii_hexdigit()));
+    /// assert_eq!(r_p.entropy_bits(), 128.0);
+    ///
+    /// assert_eq!(RandPwd::from_alphabet("", 8).unwrap_err(), PasswordError::EmptyPool("ltr"));
+    /// ```
+    #[inline]
+    pub fn from_alphabet<T: ToBigUint>(alphabet: &str, length: T) -> Result<Self, PasswordError> {
+
+        if alphabet.is_empty() {
+            return Err(PasswordError::EmptyPool("ltr"));
+        }
+
+        let mut r_p = RandPwd::new(length, 0, 0)?;
+        r_p.with_charset(Charset::custom(alphabet, "", ""))?;
+
+        Ok(r_p)
+
+    }
+
+
+    /// Return an instance of `RandPwd` which lays the categories out by `pattern`,
+    /// `L` for a letter, `S` for a symbol and `D` for a digit at each position
+    ///
+    /// The counts come from the pattern, `set_cnt` can't change them. Every position is drawn
+    /// from the pool of its category and the shuffle is skipped, only the guaranteed uppercase
+    /// and lowercase letters are put at random letter positions.
+    /// # Example
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::with_pattern("LLDSLLDS").unwrap();
+    /// r_p.join();
+    /// let kinds = r_p.val().chars().map(|ch| if ch.is_ascii_alphabetic() { 'L' } else if ch.is_ascii_digit() { 'D' } else { 'S' });
+    /// assert_eq!(kinds.collect::<String>(), "LLDSLLDS");
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// assert!(!r_p.matches_policy("ab1!cd!2"));
+    /// assert_eq!(r_p.set_cnt("sbl", 4), None);
+    ///
+    /// assert_eq!(RandPwd::with_pattern("LLx").unwrap_err(), PasswordError::Pattern('x'));
+    /// ```
+    #[inline]
+    pub fn with_pattern(pattern: &str) -> Result<Self, PasswordError> {
+
+        if let Some(ch) = pattern.chars().find(|ch| !"LSD".contains(*ch)) {
+            return Err(PasswordError::Pattern(ch));
         }
 
+        let count = |kind: char| pattern.matches(kind).count();
+        let mut r_p = RandPwd::new(count('L'), count('S'), count('D'))?;
+        r_p.shuffle = false;
+        r_p.pattern = Some(pattern.to_string());
+
+        Ok(r_p)
+
+    }
+
+
+    /// Return an instance of `RandPwd` which draws every count of `groups` from its alphabet,
+    /// then `extra_length` characters from `fill_alphabet`, and shuffles them together
+    ///
+    /// It generalizes the letters, symbols and numbers to any number of categories.
+    /// The duplicates of an alphabet are dropped, the alphabets may share characters.
+    /// The length is the sum of the counts, `set_cnt` can't change it, and the options
+    /// of the letters, symbols and numbers don't apply.
+    /// # Example
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let groups = vec![("ABCDEF".to_string(), 4u8.into()), ("αβγ".to_string(), 2u8.into())];
+    /// let mut r_p = RandPwd::from_groups(groups, 6u8.into(), "0123456789".to_string()).unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.val().chars().count(), 12);
+    /// assert_eq!(r_p.val().chars().filter(|ch| "αβγ".contains(*ch)).count(), 2);
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// assert_eq!(r_p.entropy_bits(), 4.0 * 6f64.log2() + 2.0 * 3f64.log2() + 6.0 * 10f64.log2());
+    ///
+    /// let groups = vec![("".to_string(), 1u8.into())];
+    /// assert_eq!(RandPwd::from_groups(groups, 0u8.into(), String::new()).unwrap_err(), PasswordError::EmptyPool("group"));
+    /// assert_eq!(RandPwd::from_groups(vec![], 4u8.into(), String::new()).unwrap_err(), PasswordError::EmptyPool("fill"));
+    /// ```
+    #[inline]
+    pub fn from_groups(groups: Vec<(String, BigUint)>, extra_length: BigUint, fill_alphabet: String) -> Result<Self, PasswordError> {
+
+        let fill = (fill_alphabet, extra_length, "fill");
+        let groups = groups
+            .into_iter()
+            .map(|(alphabet, cnt)| (alphabet, cnt, "group"))
+            .chain(Some(fill))
+            .map(|(alphabet, cnt, kind)| {
+                let cnt = cnt.to_usize().ok_or(PasswordError::InvalidCount("group"))?;
+                if cnt > 0 && alphabet.is_empty() {
+                    return Err(PasswordError::EmptyPool(kind));
+                }
+                let mut seen = BTreeSet::new();
+                Ok((alphabet.chars().filter(|ch| seen.insert(*ch)).collect::<String>(), cnt))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let length = groups
+            .iter()
+            .try_fold(0usize, |length, (_, cnt)| length.checked_add(*cnt))
+            .ok_or(PasswordError::InvalidCount("len"))?;
+        let mut r_p = RandPwd::new(length, 0, 0)?;
+        r_p.mode = Mode::Groups;
+        r_p.groups = groups;
+
+        Ok(r_p)
+
+    }
+
+
+    /// Return a `RandPwdBuilder` to configure `RandPwd` step by step
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().letters(16).build().unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().chars().all(|ch| ch.is_ascii_alphabetic()));
+    /// ```
+    #[inline]
+    pub fn builder() -> RandPwdBuilder {
+        RandPwdBuilder::new()
+    }
+
+
+    /// Return an instance of `RandPwd` configured by a policy like `"len>=12;digits>=2;symbols>=1"`
+    ///
+    /// The clauses are separated by `;`, each one bounds `len`, `digits`, `symbols`, `upper`
+    /// or `lower` with `>=`, `<=` or `=`. `upper` and `lower` only take `>=`.
+    /// The length is the shortest the policy allows, but at least 16 if its upper bound lets it,
+    /// and the letters fill what the digits and symbols leave. A range of digits or symbols
+    /// is drawn for every password like `RandPwdBuilder::max_numbers`.
+    /// # Example
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::from_policy("len>=12; len<=64; digits>=2; symbols>=1").unwrap();
+    /// assert_eq!(r_p.get_cnt("num").unwrap(), &2u8.into());
+    /// assert_eq!(r_p.get_cnt("sbl").unwrap(), &1u8.into());
+    /// assert_eq!(r_p.get_cnt("ltr").unwrap(), &13u8.into());
+    ///
+    /// let r_p = RandPwd::from_policy("len<=10;digits=4;upper>=1").unwrap();
+    /// for pwd in r_p.generate_many(10) {
+    ///     assert_eq!(pwd.len(), 10);
+    ///     assert_eq!(pwd.matches(|ch: char| ch.is_ascii_digit()).count(), 4);
+    ///     assert!(pwd.contains(|ch: char| ch.is_ascii_uppercase()));
+    /// }
+    ///
+    /// assert_eq!(RandPwd::from_policy("len>=12;emoji>=1").unwrap_err(), PasswordError::Policy("emoji>=1".into()));
+    /// assert_eq!(RandPwd::from_policy("len>=twelve").unwrap_err(), PasswordError::Policy("len>=twelve".into()));
+    /// assert!(matches!(RandPwd::from_policy("len<=4;digits>=5"), Err(PasswordError::LengthTooSmall { .. })));
+    /// ```
+    #[inline]
+    pub fn from_policy(policy: &str) -> Result<Self, PasswordError> {
+        policy::parse(policy)?.build()
+    }
+
+
+    /// Return an instance of `RandPwd` which generates reproducible passwords
+    /// # Example
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::with_seed(10, 2, 3, 42).unwrap();
+    /// r_p.join();
+    /// let first = r_p.val().to_string();
+    /// r_p.join();
+    /// assert_eq!(first, r_p.val());
+    /// ```
+    #[inline]
+    pub fn with_seed<L, S, N>(ltr_cnt: L, sbl_cnt: S, num_cnt: N, seed: u64) -> Result<Self, PasswordError>
+    where L: ToBigUint,
+          S: ToBigUint,
+          N: ToBigUint,
+    {
+
+        let mut r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt)?;
+        r_p.set_seed(seed);
+
+        Ok(r_p)
+
     }
 
 
@@ -62,8 +541,8 @@ impl RandPwd {
     /// # Example
     ///
     /// ```
-    /// use rpg::RandPwd;
-    /// let r_p = RandPwd::new(10, 2, 3);
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
     /// assert_eq!("", r_p.val())
     /// ```
     #[inline]
@@ -79,6 +558,43 @@ impl RandPwd {
     }
 
 
+    /// Return a copy of the configuration of `RandPwd` without its content
+    ///
+    /// `clone` copies the password too, this wipes the copy right away, see `clear`.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::with_seed(10, 2, 3, 7).unwrap();
+    /// r_p.join();
+    /// let mut other = r_p.clone_config();
+    /// assert!(other.is_empty());
+    /// other.join();
+    /// assert_eq!(other.val(), r_p.val());
+    /// ```
+    #[inline]
+    pub fn clone_config(&self) -> Self {
+        let mut r_p = self.clone();
+        _WIPE(&mut r_p.content);
+        r_p
+    }
+
+
+    /// Wipe the content of `RandPwd`
+    ///
+    /// The bytes are only overwritten with zeros when the `zeroize` feature is enabled,
+    /// otherwise the content is just cleared.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join();
+    /// r_p.clear();
+    /// assert!(r_p.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        _WIPE(&mut self.content);
+    }
+
+
     /// Return the value of `UNIT`
     #[inline]
     pub fn unit(&self) -> usize {
@@ -88,98 +604,2298 @@ impl RandPwd {
 
     /// The value of UNIT is inversely proportional to memory overhead
     /// In order to reduce the memory overhead, raise the value of `UNIT`
+    ///
+    /// Every `UNIT` characters make up a chunk which is drawn as one parallel task,
+    /// a small `UNIT` spends more time on scheduling than on drawing, a large one
+    /// leaves fewer tasks to spread over the threads and buffers more in `join_to_writer`.
+    /// It defaults to 4096, zero is treated as one.
+    ///
+    /// Every chunk draws from a generator of its own, so a seeded password past 256
+    /// characters changes with `UNIT`.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert_eq!(r_p.unit(), 4096);
+    ///
+    /// let mut r_p = RandPwd::with_seed(800, 100, 100, 7).unwrap();
+    /// r_p.with_letters("åßçđ").unwrap();
+    /// r_p.set_unit(3);
+    /// r_p.join();
+    /// let whole = r_p.val().to_string();
+    /// r_p.join();
+    /// assert_eq!(r_p.val(), whole);
+    /// assert_eq!(r_p.val().chars().count(), 1000);
+    /// ```
     #[inline]
     pub fn set_unit(&mut self, val: usize) {
-        self._UNIT = val;
+        self._UNIT = val.max(1);
     }
 
 
-    /// Returns the length of this `RandPwd`, in both bytes and [char]s.
+    /// Return the sizes of the chunks the letters drawn beyond `min_upper` and `min_lower`
+    /// are split into, one parallel task each, without generating anything
+    ///
+    /// The other categories are split the same way. With the ranges of `max_symbols`
+    /// or `max_numbers` the letters take what isn't drawn, this is the plan for the fewest letters.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().letters(10_000).min_upper(1000).build().unwrap();
+    /// assert_eq!(r_p.chunk_plan(), vec![4096, 4096, 808]);
+    ///
+    /// r_p.set_unit(3000);
+    /// assert_eq!(r_p.chunk_plan(), vec![3000, 3000, 3000]);
+    ///
+    /// assert!(RandPwd::new(0, 4, 4).unwrap().chunk_plan().is_empty());
+    /// ```
     #[inline]
-    pub fn len(&self) -> usize {
-        self.content.len()
+    pub fn chunk_plan(&self) -> Vec<usize> {
+        _DIV_UNIT(self._UNIT, &(&self.ltr_cnt - &self.min_upper - &self.min_lower))
     }
 
 
-    /// Returns true if this `RandPwd` has a length of zero, and false otherwise.
+    /// Return the seed of `RandPwd`, `None` if it's unseeded
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.content.is_empty()
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
     }
 
 
-    /// Get count of `RandPwd`
+    /// Seed `RandPwd`, every call of `join` will then produce the same password
+    ///
+    /// The draws, the shuffle, the fixups and the leet substitutions all take the seeded generator,
+    /// so two instances with the same configuration and seed produce the same password.
     /// ```
-    /// use rpg::RandPwd;
-    /// use num_traits::ToPrimitive;
-    /// let r_p = RandPwd::new(10, 2, 3);
-    /// assert_eq!(r_p.get_cnt("ltr").unwrap().to_usize().unwrap(), 10);
-    /// assert_eq!(r_p.get_cnt("sbl").unwrap().to_usize().unwrap(), 2);
-    /// assert_eq!(r_p.get_cnt("num").unwrap().to_usize().unwrap(), 3);
+    /// use rand_pwd::RandPwd;
+    /// let seeded = |seed| {
+    ///     let mut r_p = RandPwd::builder().letters(20).symbols(4).numbers(4).min_upper(3).no_sequences(true).build().unwrap();
+    ///     r_p.set_leetify(0.3);
+    ///     r_p.set_seed(seed);
+    ///     r_p.join();
+    ///     r_p
+    /// };
+    /// assert_eq!(seeded(7).val(), seeded(7).val());
+    /// assert_ne!(seeded(7).val(), seeded(8).val());
     /// ```
     #[inline]
-    pub fn get_cnt(&self, kind: &str) -> Option<&BigUint> {
-        match kind {
-            "ltr" => Some(&self.ltr_cnt),
-            "sbl" => Some(&self.sbl_cnt),
-            "num" => Some(&self.num_cnt),
-
-            _   => None,
-        }
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
     }
 
 
-    /// Change the count of letters, symbols or numbers of `RandPwd`
+    /// Return known seeds, counts of letters, symbols and numbers and the passwords
+    /// `with_seed` and `join` generate for them
+    ///
+    /// They pin down the draws and the shuffle, a change of the algorithm breaks them,
+    /// and a port of it can check itself against them.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// for &(seed, (l, s, n), pwd) in RandPwd::test_vectors() {
+    ///     let mut r_p = RandPwd::with_seed(l, s, n, seed).unwrap();
+    ///     r_p.join();
+    ///     assert_eq!(r_p.val(), pwd);
+    /// }
     /// ```
-    /// use rpg::*;
-    /// let mut r_p = RandPwd::new(10, 2, 3);
+    #[inline]
+    pub fn test_vectors() -> &'static [(u64, (u32, u32, u32), &'static str)] {
+        &vectors::TEST_VECTORS
+    }
+
+
+    /// Return whether the ambiguous characters are excluded
+    #[inline]
+    pub fn exclude_ambiguous(&self) -> bool {
+        self.exclude_ambiguous
+    }
+
+
+    /// Exclude the characters which are hard to tell apart in many fonts.
     ///
-    /// // Set the letter's count
-    /// r_p.set_cnt("ltr", 0);
+    /// The letters `l` `I` `o` `O` `B` and the numbers `0` `1` `8` are removed,
+    /// which leaves 47 letters, 32 symbols and 7 numbers to choose from.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(100, 10, 100).unwrap();
+    /// r_p.set_exclude_ambiguous(true);
     /// r_p.join();
-    /// println!("{}", r_p.val());
-    /// // Output: *029(
+    /// assert!(!r_p.val().contains(|ch| "lIoOB018".contains(ch)));
+    /// ```
+    #[inline]
+    pub fn set_exclude_ambiguous(&mut self, val: bool) {
+        self.exclude_ambiguous = val;
+    }
+
+
+    /// Return whether the passwords are generated in parallel
+    #[inline]
+    pub fn parallel(&self) -> bool {
+        self.parallel
+    }
+
+
+    /// Generate with rayon, the default, or on the calling thread only
     ///
-    /// // Set the symbol's count
-    /// r_p.set_cnt("sbl", 0);
+    /// It applies to the chunks of a long password, which are drawn from generators of their own,
+    /// and to `generate_many` and `write_many_to_file`, a seeded `RandPwd` gives the same passwords either way. Sequential is often faster
+    /// for short passwords, and safer inside a thread pool of its own or a single-threaded runtime.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::with_seed(80_000, 10_000, 10_000, 7).unwrap();
     /// r_p.join();
-    /// println!("{}", r_p.val());
-    /// // Output: nz1MriAl0j5on
+    /// let parallel = r_p.val().to_string();
     ///
-    /// // Set the number's count
-    /// r_p.set_cnt("num", 0);
+    /// let many = r_p.generate_many(3);
+    ///
+    /// r_p.set_parallel(false);
     /// r_p.join();
-    /// println!("{}", r_p.val());
-    /// // Output: +iQiQGSXl(nv
+    /// assert_eq!(r_p.val(), parallel);
+    /// assert_eq!(r_p.generate_many(3), many);
     /// ```
     #[inline]
-    pub fn set_cnt<T: ToBigUint>(&mut self, kind: &str, val: T) -> Option<()> {
-        match kind {
+    pub fn set_parallel(&mut self, val: bool) {
+        self.parallel = val;
+    }
 
-            "ltr" => self.ltr_cnt = val.to_biguint()?,
-            "sbl" => self.sbl_cnt = val.to_biguint()?,
-            "num" => self.num_cnt = val.to_biguint()?,
 
-            _     => (),
-        }
-        Some(())
+    /// Return whether `Display` isolates the password from the text around it
+    #[inline]
+    pub fn bidi_safe(&self) -> bool {
+        self.bidi_safe
     }
 
 
-    /// Generate the password for `RandPwd`
+    /// Make `Display` wrap the password in U+2066 LEFT-TO-RIGHT ISOLATE
+    /// and U+2069 POP DIRECTIONAL ISOLATE
+    ///
+    /// In a right-to-left text the letters, digits and symbols of the password are then
+    /// laid out left to right in the order they're typed. Only the display form changes:
+    /// `val`, `len`, `join_bytes`, the entropy and the other methods see the password
+    /// without the two marks, and an empty password is displayed as empty.
     /// ```
-    /// use rpg::RandPwd;
-    /// let mut r_p = RandPwd::new(10, 2, 3);
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.set_bidi_safe(true);
     /// r_p.join();
-    /// println!("{}", r_p);
+    /// assert_eq!(r_p.to_string(), format!("\u{2066}{}\u{2069}", r_p.val()));
+    /// assert_eq!(r_p.len(), 15);
+    /// assert!(r_p.matches_policy(r_p.val()));
     /// ```
     #[inline]
-    pub fn join(&mut self) {
-        let mut PWD: String = _PWD(&self);
-        // This is absolutely safe, because they are all ASCII characters except control ones.
-        let bytes = unsafe { PWD.as_bytes_mut() };
-        bytes.shuffle(&mut thread_rng());
-        self.content = bytes.par_iter().map(|s| *s as char).collect::<String>();
+    pub fn set_bidi_safe(&mut self, val: bool) {
+        self.bidi_safe = val;
+    }
+
+
+    /// Remove every character of `chars` from the pools of letters, symbols and numbers,
+    /// a custom `Charset` included, and from the consonants and vowels of pronounceable passwords
+    ///
+    /// It replaces what a previous call excluded, on top of `exclude_ambiguous`.
+    /// If it leaves a category with a non-zero count empty, nothing changes and an error is returned.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(100, 100, 10).unwrap();
+    /// r_p.exclude_chars(" '\\\"").unwrap();
+    /// r_p.join();
+    /// assert!(!r_p.val().contains(|ch| " '\\\"".contains(ch)));
+    /// assert_eq!(r_p.alphabet_size(), 94 - 3);
+    ///
+    /// assert_eq!(r_p.exclude_chars("0123456789"), Err(PasswordError::EmptyPool("num")));
+    /// assert_eq!(r_p.alphabet_size(), 94 - 3);
+    /// ```
+    #[inline]
+    pub fn exclude_chars(&mut self, chars: &str) -> Result<(), PasswordError> {
+        let old = mem::replace(&mut self.excluded, chars.to_string());
+        self.charset.validate(self).inspect_err(|_| self.excluded = old)
+    }
+
+
+    /// Normalize the case of the letters with `case`, see `CasePolicy`
+    ///
+    /// It runs after the fixups of `no_repeats` and `no_sequences` and before `set_leetify`,
+    /// which leaves the uppercase letters alone. Every character that isn't a symbol or a number
+    /// is a letter to it, which includes the words of passphrases. `min_upper` and `min_lower`
+    /// have nothing to draw from when their case is gone, `Alternating` has no case to keep.
+    /// ```
+    /// use rand_pwd::{ RandPwd, CasePolicy, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(8).min_upper(2).build().unwrap();
+    /// assert_eq!(r_p.set_case(CasePolicy::Lower), Err(PasswordError::EmptyPool("upper")));
+    /// ```
+    #[inline]
+    pub fn set_case(&mut self, case: CasePolicy) -> Result<(), PasswordError> {
+        let old = mem::replace(&mut self.case, case);
+        self.charset.validate(self).inspect_err(|_| self.case = old)
+    }
+
+
+    /// Substitute every `a`, `e`, `o` and `s` of the generated password by `@`, `3`, `0` and `$`
+    /// with the probability `prob`, which is clamped into `0.0..=1.0`, `NaN` counts as zero
+    ///
+    /// The substitution runs last, after the shuffle and the fixups of `no_repeats`
+    /// and `no_sequences`, and skips a letter whose substitute would break one of them.
+    /// The substituted letters become symbols or numbers, so `category_counts` sees them
+    /// as such, while `matches_policy` and `verify_self` take them for the letters they were.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(20, 0, 0).unwrap();
+    /// r_p.with_letters("aeos").unwrap();
+    /// r_p.set_leetify(1.0);
+    /// r_p.join();
+    /// assert!(r_p.val().chars().all(|ch| "@30$".contains(ch)));
+    /// ```
+    #[inline]
+    pub fn set_leetify(&mut self, prob: f64) {
+        self.leet = if prob.is_nan() { 0.0 } else { prob.clamp(0.0, 1.0) };
+    }
+
+
+    /// Draw the letters only from the characters of `letters`
+    /// instead of the ASCII alphabet, `exclude_ambiguous` doesn't apply to them
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(20, 2, 3).unwrap();
+    /// r_p.with_letters("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567").unwrap();
+    /// r_p.join();
+    /// assert!(!r_p.val().contains(char::is_lowercase));
+    ///
+    /// assert_eq!(r_p.with_letters(""), Err(PasswordError::EmptyPool("ltr")));
+    ///
+    /// // The guaranteed lowercase letters need some lowercase ones
+    /// r_p.set_cnt("lower", 2);
+    /// assert_eq!(r_p.with_letters("XYZ"), Err(PasswordError::EmptyPool("lower")));
+    /// ```
+    #[inline]
+    pub fn with_letters(&mut self, letters: &str) -> Result<(), PasswordError> {
+        let mut charset = self.charset.clone();
+        charset.letters = Some(_CHARS(letters));
+        self.with_charset(charset)
+    }
+
+
+    /// Draw the symbols only from the characters of `symbols`
+    /// instead of the default ASCII punctuations
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(10, 20, 3).unwrap();
+    /// r_p.with_symbols("!@#").unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.val().matches(|ch| "!@#".contains(ch)).count(), 20);
+    ///
+    /// assert_eq!(r_p.with_symbols(""), Err(PasswordError::EmptyPool("sbl")));
+    ///
+    /// // Multi-byte characters are fine as well
+    /// r_p.with_symbols("€£¥").unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.val().matches(|ch| "€£¥".contains(ch)).count(), 20);
+    /// ```
+    #[inline]
+    pub fn with_symbols(&mut self, symbols: &str) -> Result<(), PasswordError> {
+        let mut charset = self.charset.clone();
+        charset.symbols = Some(_CHARS(symbols));
+        self.with_charset(charset)
+    }
+
+
+    /// Draw the numbers only from the characters of `numbers`
+    /// instead of the default ASCII digits, like the hexadecimal digits of a token
+    ///
+    /// The letters give up the characters they share with the numbers while their count is zero.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(0, 0, 32).unwrap();
+    /// r_p.with_numbers("0123456789abcdef").unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().chars().all(|ch| ch.is_ascii_hexdigit() && !ch.is_ascii_uppercase()));
+    /// assert_eq!(r_p.alphabet_size(), 16);
+    /// assert_eq!(r_p.category_counts(), (0, 0, 32));
+    /// assert!(r_p.matches_policy(r_p.val()));
+    ///
+    /// assert_eq!(r_p.with_numbers(""), Err(PasswordError::EmptyPool("num")));
+    /// ```
+    #[inline]
+    pub fn with_numbers(&mut self, numbers: &str) -> Result<(), PasswordError> {
+        let mut charset = self.charset.clone();
+        charset.numbers = Some(_CHARS(numbers));
+        self.with_charset(charset)
+    }
+
+
+    /// Keep only the characters of the pools that `prefer` finds easy to type on `layout`,
+    /// see `TypePrefer`
+    ///
+    /// The pools shrink, so `entropy_bits`, `alphabet_size` and `combinations` go down with them.
+    /// It fails if a category with a non-zero count is left with nothing, e.g. the numbers
+    /// on the home row. The case policy and the leet substitutions come afterwards and may
+    /// bring back the characters which need shift.
+    /// ```
+    /// use rand_pwd::{ RandPwd, TypePrefer, Layout };
+    /// let mut r_p = RandPwd::new(12, 2, 2).unwrap();
+    /// let before = r_p.entropy_bits();
+    /// r_p.typeability(TypePrefer::NoShift, &Layout::qwerty()).unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().chars().all(|ch| !ch.is_ascii_uppercase() && !"!@#$%^&*()_+{}|:\"<>?~".contains(ch)));
+    /// // 26 letters, 11 symbols and 10 numbers
+    /// assert_eq!(r_p.alphabet_size(), 47);
+    /// assert!(r_p.entropy_bits() < before);
+    /// ```
+    #[inline]
+    pub fn typeability(&mut self, prefer: TypePrefer, layout: &Layout) -> Result<(), PasswordError> {
+
+        let data: &CharVec = if self.exclude_ambiguous { &DATA_UNAMBIGUOUS } else { &DATA };
+        let keep = |pool: &Option<Vec<char>>, i: usize| {
+            let pool = pool.clone().unwrap_or_else(|| data[i].to_vec());
+            Some(pool.into_iter().filter(|ch| prefer.keeps(layout, *ch)).collect())
+        };
+
+        let mut charset = self.charset.clone();
+        charset.letters = keep(&charset.letters, 0);
+        charset.symbols = keep(&charset.symbols, 1);
+        charset.numbers = keep(&charset.numbers, 2);
+        self.with_charset(charset)
+
+    }
+
+
+    /// Draw the characters from the pools of `charset`
+    ///
+    /// It fails if a category with a non-zero count would have nothing to draw from.
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset, PasswordError };
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert_eq!(r_p.with_charset(Charset::custom("abc", "", "123")), Err(PasswordError::EmptyPool("sbl")));
+    /// ```
+    #[inline]
+    pub fn with_charset(&mut self, charset: Charset) -> Result<(), PasswordError> {
+        charset.validate(self)?;
+        self.charset = charset;
+        Ok(())
+    }
+
+
+    /// Append the check characters of `kind` to every password, see `CheckKind`
+    ///
+    /// They don't count toward the length nor the entropy, they are computed from the password
+    /// once it's complete, so its constraints like `no_edge_symbols` don't apply to them.
+    /// `matches_policy` verifies them.
+    /// ```
+    /// use rand_pwd::{ RandPwd, CheckKind };
+    /// let mut r_p = RandPwd::pin(10);
+    /// r_p.with_checksum(CheckKind::LuhnModN);
+    /// r_p.join();
+    /// assert_eq!(r_p.len(), 11);
+    /// assert!(r_p.matches_policy(r_p.val()));
+    ///
+    /// // The usual Luhn check digit, and a typo is caught
+    /// assert!(r_p.matches_policy("79927398713"));
+    /// assert!(!r_p.matches_policy("79927398813"));
+    ///
+    /// let mut r_p = RandPwd::pin(9);
+    /// r_p.with_checksum(CheckKind::Crc8);
+    /// assert!(r_p.matches_policy("123456789F4"));
+    /// assert!(!r_p.matches_policy("123456798F4"));
+    ///
+    /// let r_p = RandPwd::builder().letters(10).symbols(2).numbers(2).with_checksum(CheckKind::LuhnModN).build().unwrap();
+    /// for pwd in r_p.generate_many(20) {
+    ///     assert_eq!(pwd.chars().count(), 15);
+    ///     assert!(r_p.matches_policy(&pwd));
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_checksum(&mut self, kind: CheckKind) {
+        self.checksum = Some(kind);
+    }
+
+
+    /// Draw the words of passphrases from `words` instead of the embedded list
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::passphrase(4, ' ');
+    /// r_p.with_wordlist(&["correct", "horse", "battery", "staple"]).unwrap();
+    /// r_p.join();
+    /// assert!(r_p.val().split(' ').all(|word| ["correct", "horse", "battery", "staple"].contains(&word)));
+    /// assert_eq!(r_p.entropy_bits(), 8.0);
+    ///
+    /// assert_eq!(r_p.with_wordlist(&[]), Err(PasswordError::EmptyPool("word")));
+    /// ```
+    #[inline]
+    pub fn with_wordlist(&mut self, words: &[&str]) -> Result<(), PasswordError> {
+        if words.is_empty() {
+            return Err(PasswordError::EmptyPool("word"));
+        }
+        self.wordlist = Some(words.iter().map(|word| word.to_string()).collect());
+        Ok(())
+    }
+
+
+    /// Returns the length of this `RandPwd`, in both bytes and [char]s.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+
+
+    /// Returns true if this `RandPwd` has a length of zero, and false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+
+    /// Compare the content of `RandPwd` with `other` in constant time
+    ///
+    /// It's `ConstantTimeEq` of `subtle`, every byte is compared whatever the first difference is,
+    /// so the time taken doesn't leak how much of a guess is right. Only the length is not hidden.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.set_val("correct horse");
+    /// assert!(r_p.ct_eq("correct horse"));
+    /// assert!(!r_p.ct_eq("correct house"));
+    /// assert!(!r_p.ct_eq("correct"));
+    /// ```
+    #[cfg(feature = "subtle")]
+    #[inline]
+    pub fn ct_eq(&self, other: &str) -> bool {
+        _CT_EQ(&self.content, other)
+    }
+
+
+    /// Return the length of the passwords `RandPwd` generates, `ltr + sbl + num`
+    ///
+    /// The prefix, the suffix and the check characters of `with_checksum` are not counted,
+    /// and neither is the length of a passphrase, which depends on its words. `len` is the length of the content.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::builder().letters(10).symbols(2).numbers(3).min_upper(2).min_lower(1).build().unwrap();
+    /// assert_eq!(r_p.length(), 15u8.into());
+    /// assert_eq!(r_p.letter_count(), &10u8.into());
+    /// assert_eq!(r_p.symbol_count(), &2u8.into());
+    /// assert_eq!(r_p.number_count(), &3u8.into());
+    /// // What's left of the letters after the guaranteed uppercase and lowercase ones
+    /// assert_eq!(r_p.free_letter_count(), 7u8.into());
+    /// ```
+    #[inline]
+    pub fn length(&self) -> BigUint {
+        &self.ltr_cnt + &self.sbl_cnt + &self.num_cnt
+    }
+
+
+    /// Return the count of letters, see `length`
+    #[inline]
+    pub fn letter_count(&self) -> &BigUint {
+        &self.ltr_cnt
+    }
+
+
+    /// Return the count of symbols, the most of them with `max_symbols`, see `length`
+    #[inline]
+    pub fn symbol_count(&self) -> &BigUint {
+        &self.sbl_cnt
+    }
+
+
+    /// Return the count of numbers, the most of them with `max_numbers`, see `length`
+    #[inline]
+    pub fn number_count(&self) -> &BigUint {
+        &self.num_cnt
+    }
+
+
+    /// Return the count of letters drawn from the whole letter pool,
+    /// the ones beyond `min_upper` and `min_lower`, see `length`
+    #[inline]
+    pub fn free_letter_count(&self) -> BigUint {
+        &self.ltr_cnt - &self.min_upper - &self.min_lower
+    }
+
+
+    /// Get count of `RandPwd`
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// use num_traits::ToPrimitive;
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert_eq!(r_p.get_cnt("ltr").unwrap().to_usize().unwrap(), 10);
+    /// assert_eq!(r_p.get_cnt("sbl").unwrap().to_usize().unwrap(), 2);
+    /// assert_eq!(r_p.get_cnt("num").unwrap().to_usize().unwrap(), 3);
+    /// ```
+    ///
+    /// `"upper"` and `"lower"` return the minimum count of uppercase and lowercase letters
+    #[inline]
+    pub fn get_cnt(&self, kind: &str) -> Option<&BigUint> {
+        match kind {
+            "ltr"   => Some(&self.ltr_cnt),
+            "sbl"   => Some(&self.sbl_cnt),
+            "num"   => Some(&self.num_cnt),
+            "upper" => Some(&self.min_upper),
+            "lower" => Some(&self.min_lower),
+
+            _   => None,
+        }
+    }
+
+
+    /// Change the count of letters, symbols or numbers of `RandPwd`
+    /// ```
+    /// use rand_pwd::*;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    ///
+    /// // Set the letter's count
+    /// r_p.set_cnt("ltr", 0);
+    /// r_p.join();
+    /// println!("{}", r_p.val());
+    /// // Output: *029(
+    ///
+    /// // Set the symbol's count
+    /// r_p.set_cnt("sbl", 0);
+    /// r_p.join();
+    /// println!("{}", r_p.val());
+    /// // Output: nz1MriAl0j5on
+    ///
+    /// // Set the number's count
+    /// r_p.set_cnt("num", 0);
+    /// r_p.join();
+    /// println!("{}", r_p.val());
+    /// // Output: +iQiQGSXl(nv
+    ///
+    /// // Set the minimum count of uppercase letters
+    /// r_p.set_cnt("ltr", 10);
+    /// r_p.set_cnt("upper", 4);
+    ///
+    /// // The letters can't be fewer than the guaranteed uppercase and lowercase ones
+    /// assert_eq!(r_p.set_cnt("ltr", 3), None);
+    /// ```
+    #[inline]
+    pub fn set_cnt<T: ToBigUint>(&mut self, kind: &str, val: T) -> Option<()> {
+        let val = val.to_biguint()?;
+        match kind {
+
+            "ltr" | "sbl" | "num" if self.pattern.is_some() || self.mode == Mode::Groups => return None,
+
+            "ltr"   if val < &self.min_upper + &self.min_lower => return None,
+            "upper" if &val + &self.min_lower > self.ltr_cnt   => return None,
+            "lower" if &val + &self.min_upper > self.ltr_cnt   => return None,
+
+            "ltr"   => self.ltr_cnt = val,
+            "sbl"   => self.sbl_cnt = val,
+            "num"   => self.num_cnt = val,
+            "upper" => self.min_upper = val,
+            "lower" => self.min_lower = val,
+
+            _     => (),
+        }
+        Some(())
+    }
+
+
+    /// Return the entropy in bits of the passwords this `RandPwd` generates
+    ///
+    /// Every category contributes `count * log2(pool_size)`, the pools reflect
+    /// `exclude_ambiguous` and the custom symbols, the guaranteed uppercase and lowercase
+    /// letters only count their own halves of the letters.
+    ///
+    /// The positions chosen by the shuffle are not taken into account, so forcing the
+    /// counts of symbols and numbers is treated as a loss against drawing every character
+    /// from the whole alphabet, which makes the estimate a lower bound.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(0, 0, 4).unwrap();
+    /// assert!((r_p.entropy_bits() - 4.0 * 10f64.log2()).abs() < 1e-9);
+    ///
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert!(r_p.entropy_bits() > 60.0);
+    /// ```
+    ///
+    /// A passphrase has `word_cnt * log2(wordlist_len)` bits. A pronounceable password
+    /// counts the bits of a vowel for half of its letters rounded up and of a consonant
+    /// for the rest, the symbols and numbers count the same.
+    ///
+    /// With `max_symbols` or `max_numbers` the counts that give the fewest bits are taken.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn entropy_bits(&self) -> f64 {
+        match self.mode {
+            Mode::Chars => {
+                let pools = _POOLS(self);
+                pools
+                    .iter()
+                    .filter(|(cnt, _)| !cnt.is_zero())
+                    .map(|(cnt, pool)| cnt.to_f64().unwrap() * (pool.len() as f64).log2())
+                    .sum::<f64>()
+                    + self.floor_bits(&pools, pools[0].1.len())
+            }
+            Mode::Passphrase { word_cnt, .. } => word_cnt as f64 * (_WORDS(self).len() as f64).log2(),
+            Mode::Groups => {
+                self.groups
+                    .iter()
+                    .filter(|(_, cnt)| *cnt > 0)
+                    .map(|(alphabet, cnt)| *cnt as f64 * (alphabet.chars().count() as f64).log2())
+                    .sum()
+            }
+            Mode::Pronounceable => {
+                let (consonants, vowels) = _SYLLABLE_POOLS(self);
+                let l = self.ltr_cnt.to_f64().unwrap();
+                let bits = |cnt: f64, pool: usize| cnt * (pool as f64).log2();
+
+                let pools = _POOLS(self);
+
+                bits((l / 2.0).floor(), consonants.len()) + bits((l / 2.0).ceil(), vowels.len())
+                    + pools[3..]
+                        .iter()
+                        .filter(|(cnt, _)| !cnt.is_zero())
+                        .map(|(cnt, pool)| bits(cnt.to_f64().unwrap(), pool.len()))
+                        .sum::<f64>()
+                    + self.floor_bits(&pools, vowels.len())
+            }
+        }
+    }
+
+
+    /// The bits lost at worst by drawing fewer symbols or numbers than their counts,
+    /// the letters drawn from `letter_pool` characters instead are no better in that case
+    #[cfg(feature = "std")]
+    #[inline]
+    fn floor_bits(&self, pools: &[(BigUint, Vec<char>)], letter_pool: usize) -> f64 {
+        [(3, &self.sbl_floor), (4, &self.num_floor)]
+            .iter()
+            .filter_map(|(i, floor)| floor.as_ref().map(|floor| (i, (&pools[*i].0 - floor).to_f64().unwrap())))
+            .filter(|(_, range)| *range > 0.0)
+            .map(|(i, range)| (range * ((letter_pool as f64).log2() - (pools[*i].1.len() as f64).log2())).min(0.0))
+            .sum()
+    }
+
+
+    /// Classify the configuration by `entropy_bits`, see `Strength` for the thresholds
+    /// ```
+    /// use rand_pwd::{ RandPwd, Strength };
+    /// assert_eq!(RandPwd::pin(4).strength(), Strength::Weak);
+    /// assert_eq!(RandPwd::new(10, 2, 3).unwrap().strength(), Strength::Medium);
+    /// assert_eq!(RandPwd::new(16, 2, 2).unwrap().strength(), Strength::Strong);
+    ///
+    /// let mut r_p = RandPwd::new(16, 2, 2).unwrap();
+    /// r_p.with_letters("ab").unwrap();
+    /// assert_eq!(r_p.strength(), Strength::Weak);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn strength(&self) -> Strength {
+        Strength::from_bits(self.entropy_bits())
+    }
+
+
+    /// Return the entropy in bits and the number of passwords, see `EntropyReport`
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset, Strength };
+    /// let mut r_p = RandPwd::new(0, 0, 8).unwrap();
+    /// r_p.with_charset(Charset::custom("", "", "01")).unwrap();
+    /// let report = r_p.entropy_report();
+    /// assert_eq!(report.bits, 8.0);
+    /// assert_eq!(report.guesses, Some(256u32.into()));
+    /// assert_eq!(report.strength(), Strength::Weak);
+    ///
+    /// // The positions of the two categories are guesses too
+    /// let report = RandPwd::new(4, 0, 4).unwrap().entropy_report();
+    /// assert!((report.guesses.unwrap().bits() as f64) > report.bits);
+    ///
+    /// // Too many to count
+    /// let report = RandPwd::new(u64::MAX, 0, 0).unwrap().entropy_report();
+    /// assert_eq!(report.guesses, None);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn entropy_report(&self) -> EntropyReport {
+        EntropyReport { bits: self.entropy_bits(), guesses: self.combinations() }
+    }
+
+
+    /// Return the average seconds a brute force needs to guess the password at
+    /// `guesses_per_second`, half of `combinations` divided by the rate
+    ///
+    /// It saturates to `f64::INFINITY` when the number of guesses overflows `f64`,
+    /// which `entropy_bits` tells before anything is counted, or when `combinations`
+    /// is too large to count or the rate isn't positive.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// // 10_000 PINs at 1000 guesses a second
+    /// assert_eq!(RandPwd::pin(4).crack_time_seconds(1000.0), 5.0);
+    ///
+    /// assert_eq!(RandPwd::new(1000, 0, 0).unwrap().crack_time_seconds(1e12), f64::INFINITY);
+    /// assert_eq!(RandPwd::pin(4).crack_time_seconds(0.0), f64::INFINITY);
+    /// let count = u64::from(u32::MAX) + 1;
+    /// assert_eq!(RandPwd::new(count, count, 0).unwrap().crack_time_seconds(1e12), f64::INFINITY);
+    /// let r_p = RandPwd::builder().letters(3000).min_upper(1).min_lower(1).build().unwrap();
+    /// assert_eq!(r_p.crack_time_seconds(1e12), f64::INFINITY);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn crack_time_seconds(&self, guesses_per_second: f64) -> f64 {
+
+        // `entropy_bits` never counts more than `combinations`, and it's cheap
+        if guesses_per_second.is_nan() || guesses_per_second <= 0.0 || self.entropy_bits() > f64::MAX_EXP as f64 {
+            return f64::INFINITY;
+        }
+
+        // Past `f64::MAX` the conversion can't be trusted to saturate
+        let guesses = match self.combinations() {
+            Some(guesses) if guesses.bits() <= f64::MAX_EXP as u64 => guesses,
+            _ => return f64::INFINITY,
+        };
+
+        guesses.to_f64().unwrap_or(f64::INFINITY) / 2.0 / guesses_per_second
+
+    }
+
+
+    /// Return the minimum length whose password drawn from every pool of `charset`
+    /// has at least `bits` of entropy, rounded up
+    ///
+    /// It's zero if `bits` isn't positive, none if no length gets there because
+    /// the alphabet has fewer than two characters or `bits` isn't finite.
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset };
+    /// use num_bigint::BigUint;
+    /// // log2(94) is about 6.55
+    /// assert_eq!(RandPwd::length_for_bits(128.0, &Charset::default()), Some(BigUint::from(20u8)));
+    /// assert_eq!(RandPwd::length_for_bits(64.0, &Charset::custom("abcdef", "", "0123456789")), Some(BigUint::from(16u8)));
+    /// assert_eq!(RandPwd::length_for_bits(1e6, &Charset::custom("a", "", "0")), Some(BigUint::from(1_000_000u32)));
+    /// assert_eq!(RandPwd::length_for_bits(0.0, &Charset::default()), Some(BigUint::from(0u8)));
+    ///
+    /// assert_eq!(RandPwd::length_for_bits(80.0, &Charset::custom("a", "", "")), None);
+    /// assert_eq!(RandPwd::length_for_bits(f64::INFINITY, &Charset::default()), None);
+    /// assert_eq!(RandPwd::length_for_bits(f64::NAN, &Charset::default()), None);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn length_for_bits(bits: f64, charset: &Charset) -> Option<BigUint> {
+
+        let mut r_p = RandPwd::new(1, 1, 1).unwrap();
+        r_p.charset = charset.clone();
+        let per_char = (r_p.alphabet_size() as f64).log2();
+
+        if !bits.is_finite() {
+            return None;
+        }
+        if bits <= 0.0 {
+            return Some(BigUint::zero());
+        }
+        if per_char <= 0.0 {
+            return None;
+        }
+        BigUint::from_f64((bits / per_char).ceil())
+
+    }
+
+
+    /// Split the uppercase hex SHA-1 of the content into the 5 digits prefix and the rest,
+    /// for the range API of Have I Been Pwned
+    ///
+    /// Only the prefix leaves the machine, in the request to
+    /// `https://api.pwnedpasswords.com/range/{prefix}` of `is_pwned`,
+    /// then `is_pwned_in` looks for the rest in the response.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(8, 0, 0).unwrap();
+    /// r_p.set_val("password");
+    /// let (prefix, suffix) = r_p.pwned_range();
+    /// assert_eq!(prefix, "5BAA6");
+    /// assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+    ///
+    /// // The test vectors of FIPS 180
+    /// let mut sha1 = |msg: &str| {
+    ///     r_p.set_val(msg);
+    ///     let (prefix, suffix) = r_p.pwned_range();
+    ///     prefix + &suffix
+    /// };
+    /// assert_eq!(sha1(""), "DA39A3EE5E6B4B0D3255BFEF95601890AFD80709");
+    /// assert_eq!(sha1("abc"), "A9993E364706816ABA3E25717850C26C9CD0D89D");
+    /// assert_eq!(sha1("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "84983E441C3BD26EBAAE4AA1F95129E5E54670F1");
+    /// assert_eq!(sha1(&"a".repeat(1_000_000)), "34AA973CD4C4DAA4F61EEB2BDBAD27316534016F");
+    /// ```
+    #[cfg(feature = "hibp")]
+    #[inline]
+    pub fn pwned_range(&self) -> (String, String) {
+        let mut hex = Sha1::digest(self.content.as_bytes())
+            .iter()
+            .flat_map(|byte| vec![byte >> 4, byte & 0xF])
+            .map(|digit| char::from_digit(digit as u32, 16).unwrap().to_ascii_uppercase())
+            .collect::<String>();
+        let suffix = hex.split_off(5);
+
+        (hex, suffix)
+    }
+
+
+    /// Whether `response`, the body returned by the range API of Have I Been Pwned
+    /// for the prefix of `pwned_range`, lists the content as breached
+    ///
+    /// Every line is a suffix and a count like `"1E4C9B93F3F0682250B6CF8331B7EE68FD8:3861493"`,
+    /// the padding lines with a count of zero don't count.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(8, 0, 0).unwrap();
+    /// r_p.set_val("password");
+    /// assert!(r_p.is_pwned_in("0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:3861493"));
+    /// assert!(!r_p.is_pwned_in("1E4C9B93F3F0682250B6CF8331B7EE68FD8:0"));
+    /// assert!(!r_p.is_pwned_in(""));
+    /// ```
+    #[cfg(feature = "hibp")]
+    #[inline]
+    pub fn is_pwned_in(&self, response: &str) -> bool {
+        let (_, suffix) = self.pwned_range();
+        response
+            .lines()
+            .filter_map(|line| line.trim().split_once(':'))
+            .any(|(hash, cnt)| hash.eq_ignore_ascii_case(&suffix) && cnt.trim().parse::<u64>().is_ok_and(|cnt| cnt > 0))
+    }
+
+
+    /// Whether the content is listed as breached by Have I Been Pwned
+    ///
+    /// Only the prefix of `pwned_range` is sent, with padding requested, and the response
+    /// is checked by `is_pwned_in`. It must run in a `tokio` runtime, a failed request
+    /// is returned as `PasswordError::Pwned`.
+    /// ```no_run
+    /// use rand_pwd::RandPwd;
+    /// # async fn check() {
+    /// let mut r_p = RandPwd::new(8, 0, 0).unwrap();
+    /// r_p.set_val("password");
+    /// assert!(r_p.is_pwned().await.unwrap());
+    /// # }
+    /// ```
+    #[cfg(feature = "hibp")]
+    #[inline]
+    pub async fn is_pwned(&self) -> Result<bool, PasswordError> {
+        let (prefix, _) = self.pwned_range();
+        let failed = |err: reqwest::Error| PasswordError::Pwned(err.to_string());
+        let response = reqwest::Client::new()
+            .get(format!("https://api.pwnedpasswords.com/range/{}", prefix))
+            .header(reqwest::header::USER_AGENT, "rand_pwd")
+            .header("Add-Padding", "true")
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(failed)?
+            .text()
+            .await
+            .map_err(failed)?;
+
+        Ok(self.is_pwned_in(&response))
+    }
+
+
+    /// Return the score from 0 to 4 that `zxcvbn` gives the content
+    ///
+    /// Unlike `entropy_bits` it judges the content itself, so a random password that happens
+    /// to spell a word scores lower.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(8, 0, 0).unwrap();
+    /// r_p.set_val("password");
+    /// assert_eq!(r_p.zxcvbn_score(), 0);
+    ///
+    /// let mut r_p = RandPwd::new(16, 2, 2).unwrap();
+    /// r_p.set_val("k#8Tq!zR2mWx9vLp3Yb&");
+    /// assert_eq!(r_p.zxcvbn_score(), 4);
+    /// ```
+    #[cfg(feature = "zxcvbn")]
+    #[inline]
+    pub fn zxcvbn_score(&self) -> u8 {
+        zxcvbn::zxcvbn(&self.content, &[]).score().into()
+    }
+
+
+    /// Generate passwords until `zxcvbn_score` reaches `min`, or fail after `MAX_DRAWS`
+    /// passwords like `join_matching`
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::passphrase(4, '-');
+    /// r_p.join_min_score(3).unwrap();
+    /// assert!(r_p.zxcvbn_score() >= 3);
+    ///
+    /// // Ten thousand PINs are far too few
+    /// let mut r_p = RandPwd::pin(4);
+    /// assert_eq!(r_p.join_min_score(4), Err(PasswordError::ConstraintUnsatisfiable("zxcvbn")));
+    /// assert_eq!(r_p.val(), "");
+    /// ```
+    #[cfg(feature = "zxcvbn")]
+    #[inline]
+    pub fn join_min_score(&mut self, min: u8) -> Result<(), PasswordError> {
+
+        if min > 4 {
+            return Err(PasswordError::ConstraintUnsatisfiable("zxcvbn"));
+        }
+
+        match self.join_matching(|pwd| u8::from(zxcvbn::zxcvbn(pwd, &[]).score()) >= min, MAX_DRAWS) {
+            Err(PasswordError::ConstraintUnsatisfiable("predicate")) => Err(PasswordError::ConstraintUnsatisfiable("zxcvbn")),
+            res => res,
+        }
+
+    }
+
+
+    /// Return the number of distinct characters the password is drawn from
+    ///
+    /// The pools of letters, symbols and numbers whose counts are non-zero are merged,
+    /// after `exclude_ambiguous` and a custom `Charset` took effect, so a character
+    /// shared by several pools is counted once. A pronounceable password merges the
+    /// consonants and vowels instead of the letters, a passphrase returns the size of its wordlist.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert_eq!(r_p.alphabet_size(), 94);
+    ///
+    /// // `0` is taken from the numbers by the symbols
+    /// r_p.with_symbols("-_0").unwrap();
+    /// assert_eq!(r_p.alphabet_size(), 52 + 3 + 9);
+    ///
+    /// // `lIoOB` and `018` are gone, but the custom `0` is kept
+    /// r_p.set_exclude_ambiguous(true);
+    /// assert_eq!(r_p.alphabet_size(), 47 + 3 + 7);
+    ///
+    /// assert_eq!(RandPwd::new(0, 0, 3).unwrap().alphabet_size(), 10);
+    /// ```
+    #[inline]
+    pub fn alphabet_size(&self) -> usize {
+
+        if let Mode::Passphrase { .. } = self.mode {
+            return _WORDS(self).len();
+        }
+        if self.mode == Mode::Groups {
+            return self.groups.iter().filter(|(_, cnt)| *cnt > 0).flat_map(|(alphabet, _)| alphabet.chars()).collect::<BTreeSet<_>>().len();
+        }
+
+        let mut sets = _POOL_SETS(self);
+        if self.mode == Mode::Pronounceable {
+            let (consonants, vowels) = _SYLLABLE_POOLS(self);
+            sets[0] = consonants.into_iter().chain(vowels).collect();
+        }
+
+        [(0, &self.ltr_cnt), (3, &self.sbl_cnt), (4, &self.num_cnt)]
+            .iter()
+            .filter(|(_, cnt)| !cnt.is_zero())
+            .flat_map(|(i, _)| sets[*i].iter())
+            .collect::<BTreeSet<_>>()
+            .len()
+
+    }
+
+
+    /// Return how many letters, symbols and numbers the content of `RandPwd` has
+    ///
+    /// Every character is looked up in the configured pools, in the order of letters,
+    /// symbols and numbers, the ones in none of them are not counted.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.category_counts(), (10, 2, 3));
+    /// ```
+    #[inline]
+    pub fn category_counts(&self) -> (usize, usize, usize) {
+        _CATEGORY_COUNTS(&_POOL_SETS(self), &self.content)
+    }
+
+
+    /// Return how many different characters the content of `RandPwd` has,
+    /// counting `char`s rather than bytes
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::from_alphabet("äöü", 20).unwrap();
+    /// r_p.join_matching(|pwd| pwd.contains('ä') && pwd.contains('ö') && pwd.contains('ü'), 100).unwrap();
+    /// assert_eq!(r_p.distinct_char_count(), 3);
+    /// // Each of them takes two bytes
+    /// assert_eq!(r_p.len(), 40);
+    /// ```
+    #[inline]
+    pub fn distinct_char_count(&self) -> usize {
+        self.content.chars().collect::<BTreeSet<_>>().len()
+    }
+
+
+    /// Whether `candidate` could have been generated by `RandPwd`
+    ///
+    /// It must have exactly the configured counts of letters, symbols and numbers
+    /// from the configured pools and no other character, at least `min_upper` uppercase
+    /// and `min_lower` lowercase letters, the distinct symbols and numbers asked by the builder,
+    /// and obey `no_repeats`, `no_sequences` and `all_distinct`. The prefix and the suffix
+    /// of the builder and the check characters of `with_checksum` must match,
+    /// the rest is checked without them.
+    /// Without the shuffle the letters, symbols and numbers must come in that order.
+    /// A passphrase must have `word_cnt` words of the wordlist joined by the separator,
+    /// the letters of a pronounceable password must alternate between consonants and vowels.
+    /// With `set_leetify` a substitute is taken for its letter where the counts need one.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join();
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// assert!(r_p.matches_policy("abcdefghij!?123"));
+    /// assert!(!r_p.matches_policy("abcdefghijk!123"));
+    /// assert!(!r_p.matches_policy("abcdefghij!?12é"));
+    ///
+    /// let r_p = RandPwd::builder().letters(4).with_letters("ab").build().unwrap();
+    /// assert!(r_p.matches_policy("abba"));
+    /// assert!(!r_p.matches_policy("abca"));
+    /// ```
+    #[inline]
+    pub fn matches_policy(&self, candidate: &str) -> bool {
+
+        let literal = match self.strip_extras(candidate) {
+            Some(body) => body.chars().collect::<Vec<_>>(),
+            None       => return false,
+        };
+
+        let sets = _POOL_SETS(self);
+        let decoded = self.unleet(&sets, &literal);
+        let candidate = decoded.as_str();
+        let chars = candidate.chars().collect::<Vec<_>>();
+        let cased = chars
+            .iter()
+            .enumerate()
+            .all(|(i, ch)| sets[3].contains(ch) || sets[4].contains(ch) || self.case.map(*ch, i) == *ch);
+
+        if let Mode::Passphrase { word_cnt, separator } = self.mode {
+            let words = _WORDS(self);
+            let parts = candidate.split(separator).collect::<Vec<_>>();
+            let known = |part: &&str| match self.case {
+                CasePolicy::AsIs => words.contains(part),
+                _ => words.iter().any(|word| word.to_lowercase() == part.to_lowercase()),
+            };
+            return cased && parts.len() == word_cnt && parts.iter().all(known);
+        }
+
+        // The alphabets may share characters, so every group only needs enough of its own
+        if self.mode == Mode::Groups {
+            let length = self.groups.iter().map(|(_, cnt)| cnt).sum::<usize>();
+            return cased
+                && chars.len() == length
+                && chars.iter().all(|ch| self.groups.iter().any(|(alphabet, cnt)| *cnt > 0 && alphabet.contains(*ch)))
+                && self.groups.iter().all(|(alphabet, cnt)| chars.iter().filter(|ch| alphabet.contains(**ch)).count() >= *cnt);
+        }
+
+        let total = &self.ltr_cnt + &self.sbl_cnt + &self.num_cnt;
+        let fits = |got: usize, cnt: &BigUint, floor: &Option<BigUint>| {
+            let got = BigUint::from(got);
+            match floor {
+                Some(floor) => floor <= &got && &got <= cnt,
+                None        => &got == cnt,
+            }
+        };
+
+        if self.mode == Mode::Pronounceable {
+            let (consonants, vowels) = _SYLLABLE_POOLS(self);
+            let letters = chars
+                .iter()
+                .filter(|ch| !sets[3].contains(ch) && !sets[4].contains(ch))
+                .map(|ch| CasePolicy::Lower.map(*ch, 0))
+                .map(|ch| if vowels.contains(&ch) { Some(true) } else if consonants.contains(&ch) { Some(false) } else { None })
+                .collect::<Option<Vec<_>>>();
+            let (_, s, n) = _CATEGORY_COUNTS(&sets, candidate);
+
+            return match letters {
+                Some(letters) => {
+                    cased
+                        && letters.windows(2).all(|w| w[0] != w[1])
+                        && BigUint::from(chars.len()) == total
+                        && fits(s, &self.sbl_cnt, &self.sbl_floor)
+                        && fits(n, &self.num_cnt, &self.num_floor)
+                }
+                None => false,
+            };
+        }
+
+        let (l, s, n) = _CATEGORY_COUNTS(&sets, candidate);
+        let count = |i: usize| BigUint::from(chars.iter().filter(|ch| sets[i].contains(ch)).count());
+        let distinct = |i: usize| chars.iter().filter(|ch| sets[i].contains(ch)).collect::<BTreeSet<_>>().len();
+
+        let kinds = chars
+            .iter()
+            .map(|ch| if sets[0].contains(ch) { 0 } else if sets[3].contains(ch) { 1 } else { 2 })
+            .collect::<Vec<_>>();
+
+        cased
+            && l + s + n == chars.len()
+            && BigUint::from(chars.len()) == total
+            && match &self.pattern {
+                Some(pattern) => kinds.iter().zip(pattern.chars()).all(|(kind, ch)| *kind == _PATTERN_KIND(ch)),
+                None          => self.shuffle || kinds.windows(2).all(|w| w[0] <= w[1]),
+            }
+            && fits(s, &self.sbl_cnt, &self.sbl_floor)
+            && fits(n, &self.num_cnt, &self.num_floor)
+            && count(1) >= self.min_upper
+            && count(2) >= self.min_lower
+            && distinct(3) >= self.min_distinct_sbl
+            && distinct(4) >= self.min_distinct_num
+            && !(self.all_distinct && literal.iter().collect::<BTreeSet<_>>().len() < literal.len())
+            && !(self.no_repeats && literal.windows(2).any(|w| w[0] == w[1]))
+            && !(self.no_sequences && (2..literal.len()).any(|i| _IS_SEQUENCE(&literal, i)))
+            && !(self.no_edge_symbols && (literal.first().into_iter().chain(literal.last())).any(|ch| sets[3].contains(ch)))
+
+    }
+
+
+    /// Check the content of `RandPwd` against its own configuration,
+    /// a self-check of the generation for the tests and the debug builds
+    ///
+    /// The length, the symbols and the numbers are checked first and named by the error,
+    /// the rest of `matches_policy` fails with `"policy"`. The content of `join_hex`,
+    /// `join_base64` or `join_grouped` doesn't pass.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(8).symbols(2).numbers(3).prefix("id-").build().unwrap();
+    /// r_p.join();
+    /// assert_eq!(r_p.verify_self(), Ok(()));
+    ///
+    /// r_p.set_val("id-abcdefgh!?1234");
+    /// assert_eq!(r_p.verify_self(), Err(PasswordError::ConstraintUnsatisfiable("len")));
+    /// r_p.set_val("id-abcdefgh!?!12");
+    /// assert_eq!(r_p.verify_self(), Err(PasswordError::ConstraintUnsatisfiable("sbl")));
+    /// r_p.set_val("id-abcdefgh!?1a2");
+    /// assert_eq!(r_p.verify_self(), Err(PasswordError::ConstraintUnsatisfiable("num")));
+    /// r_p.set_val("abcdefgh!?123");
+    /// assert_eq!(r_p.verify_self(), Err(PasswordError::ConstraintUnsatisfiable("policy")));
+    ///
+    /// // The substitutes of `set_leetify` count as the letters they were
+    /// let mut r_p = RandPwd::new(12, 2, 2).unwrap();
+    /// r_p.set_leetify(1.0);
+    /// for _ in 0..20 {
+    ///     r_p.join();
+    ///     assert_eq!(r_p.verify_self(), Ok(()));
+    /// }
+    /// r_p.set_val("p@ssw0rdaaaa!?12");
+    /// assert_eq!(r_p.verify_self(), Ok(()));
+    /// ```
+    #[inline]
+    pub fn verify_self(&self) -> Result<(), PasswordError> {
+
+        let counted = matches!(self.mode, Mode::Chars | Mode::Pronounceable);
+        if let Some(body) = self.strip_extras(&self.content).filter(|_| counted) {
+            let sets = _POOL_SETS(self);
+            let (_, s, n) = _CATEGORY_COUNTS(&sets, &self.unleet(&sets, &body.chars().collect::<Vec<_>>()));
+            let fits = |got: usize, cnt: &BigUint, floor: &Option<BigUint>| {
+                let got = BigUint::from(got);
+                floor.as_ref().map_or(&got == cnt, |floor| floor <= &got && &got <= cnt)
+            };
+
+            if BigUint::from(body.chars().count()) != self.length() {
+                return Err(PasswordError::ConstraintUnsatisfiable("len"));
+            }
+            if !fits(s, &self.sbl_cnt, &self.sbl_floor) {
+                return Err(PasswordError::ConstraintUnsatisfiable("sbl"));
+            }
+            if !fits(n, &self.num_cnt, &self.num_floor) {
+                return Err(PasswordError::ConstraintUnsatisfiable("num"));
+            }
+        }
+
+        if self.matches_policy(&self.content) {
+            Ok(())
+        } else {
+            Err(PasswordError::ConstraintUnsatisfiable("policy"))
+        }
+
+    }
+
+
+    /// Turn the substitutes of `set_leetify` in `body` back into their letters where a letter was drawn
+    ///
+    /// The words of a passphrase are letters only, a pattern tells the letters apart, otherwise
+    /// the first substitutes beyond the counts of symbols and numbers were letters, which come
+    /// first without the shuffle. The alphabets of `from_groups` are left as they are.
+    #[inline]
+    fn unleet(&self, sets: &[BTreeSet<char>], body: &[char]) -> String {
+
+        if self.leet == 0.0 || self.mode == Mode::Groups {
+            return body.iter().collect();
+        }
+
+        let pattern = self.pattern.as_ref().map(|pattern| pattern.chars().collect::<Vec<_>>());
+        let (_, s, n) = _CATEGORY_COUNTS(sets, &body.iter().collect::<String>());
+        let beyond = |got: usize, cnt: &BigUint| got.saturating_sub(cnt.to_usize().unwrap_or(usize::MAX));
+        let mut extra = [beyond(s, &self.sbl_cnt), beyond(n, &self.num_cnt)];
+
+        body
+            .iter()
+            .enumerate()
+            .map(|(i, ch)| {
+                let letter = match LEET.iter().find(|(_, leet)| leet == ch) {
+                    Some(&(letter, _)) => letter,
+                    None               => return *ch,
+                };
+                let kind = if sets[3].contains(ch) { 0 } else if sets[4].contains(ch) { 1 } else { return letter };
+
+                let drawn = match (&self.mode, &pattern) {
+                    (Mode::Passphrase { .. }, _) => true,
+                    (_, Some(pattern)) => pattern.get(i) == Some(&'L'),
+                    _ => extra[kind] > 0,
+                };
+                if drawn {
+                    extra[kind] = extra[kind].saturating_sub(1);
+                    letter
+                } else {
+                    *ch
+                }
+            })
+            .collect()
+
+    }
+
+
+    /// Split the prefix, the suffix and the check characters off `candidate`,
+    /// none if one of them doesn't match
+    #[inline]
+    fn strip_extras<'a>(&self, candidate: &'a str) -> Option<&'a str> {
+
+        let body = candidate.strip_prefix(self.prefix.as_str())?.strip_suffix(self.suffix.as_str())?;
+
+        match self.checksum {
+            Some(kind) => kind.strip(self, body),
+            None       => Some(body),
+        }
+
+    }
+
+
+    /// Return the exact number of passwords this `RandPwd` can generate
+    ///
+    /// The shuffle may put the categories anywhere, so the count is
+    /// `(ltr + sbl + num)! / (ltr! * sbl! * num!) * letters * symbols^sbl * numbers^num`,
+    /// where `letters` is `ltr_pool^ltr`, or the number of letter strings with at least
+    /// `min_upper` uppercase and `min_lower` lowercase letters.
+    ///
+    /// The pools never share characters, so it's exact, but it doesn't rule out
+    /// what `no_repeats`, `no_sequences`, `all_distinct` or the distinct symbols and numbers would reject. A passphrase has `wordlist_len^word_cnt`,
+    /// a pronounceable password counts the consonants and vowels of both possible first letters.
+    /// Without the shuffle the positions of the categories are fixed, the multinomial is dropped.
+    /// With `max_symbols` or `max_numbers` the numbers of every count that can be drawn add up.
+    ///
+    /// It's `None` when the count may take more than 65536 bits, which the counts
+    /// the crate accepts easily reach, or would add up more than 1024 terms, see `entropy_bits` for those.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(0, 0, 4).unwrap();
+    /// assert_eq!(r_p.combinations(), Some(10_000u32.into()));
+    ///
+    /// // Which 2 of the 3 slots are letters, times the letters and the number
+    /// let r_p = RandPwd::new(2, 0, 1).unwrap();
+    /// assert_eq!(r_p.combinations(), Some((3 * 52 * 52 * 10u32).into()));
+    ///
+    /// // "aB", "Ba" and "BB"
+    /// let r_p = RandPwd::builder().letters(2).min_upper(1).with_letters("aB").build().unwrap();
+    /// assert_eq!(r_p.combinations(), Some(3u8.into()));
+    ///
+    /// // 27 strings, but the 8 without "B" and the one without "a" nor "c"
+    /// let r_p = RandPwd::builder().letters(3).min_upper(1).min_lower(1).with_letters("aBc").build().unwrap();
+    /// assert_eq!(r_p.combinations(), Some(18u8.into()));
+    ///
+    /// let r_p = RandPwd::new(u64::MAX, 0, 0).unwrap();
+    /// assert_eq!(r_p.combinations(), None);
+    /// let r_p = RandPwd::builder().length(3000).max_symbols(1000).max_numbers(1000).build().unwrap();
+    /// assert_eq!(r_p.combinations(), None);
+    /// ```
+    #[inline]
+    pub fn combinations(&self) -> Option<BigUint> {
+
+        if self.combination_bits()? > MAX_COMBINATION_BITS || self.combination_terms()? > MAX_COMBINATION_TERMS {
+            return None;
+        }
+
+        Some(self.combinations_unbounded())
+
+    }
+
+
+    /// An upper bound of the bits of `combinations`, none if it doesn't fit a `u64`
+    ///
+    /// Every character takes at most the bits of the largest pool, and a category
+    /// at most the bits of the number of categories for its positions.
+    #[inline]
+    fn combination_bits(&self) -> Option<u64> {
+
+        let bits = |size: usize| 64 - (size as u64).leading_zeros() as u64;
+        let (total, largest, kinds) = match self.mode {
+            Mode::Passphrase { word_cnt, .. } => (BigUint::from(word_cnt), _WORDS(self).len(), 1),
+            Mode::Groups => (
+                BigUint::from(self.groups.iter().map(|(_, cnt)| cnt).sum::<usize>()),
+                self.groups.iter().map(|(alphabet, _)| alphabet.chars().count()).max().unwrap_or(0),
+                self.groups.len(),
+            ),
+            _ => {
+                let (consonants, vowels) = _SYLLABLE_POOLS(self);
+                let largest = _POOLS(self).iter().map(|(_, pool)| pool.len()).chain([consonants.len(), vowels.len()]).max().unwrap_or(0);
+                (&self.ltr_cnt + &self.sbl_cnt + &self.num_cnt, largest, 3)
+            }
+        };
+
+        total.to_u64()?.checked_mul(bits(largest) + bits(kinds))
+
+    }
+
+
+    /// The number of terms `combinations` adds up, none if it doesn't fit a `u64`
+    ///
+    /// Every count the ranges of `max_symbols` and `max_numbers` allow is a term, times the
+    /// letters short of `min_upper` or `min_lower` which are taken out of the shuffled ones.
+    #[inline]
+    fn combination_terms(&self) -> Option<u64> {
+
+        let cnt = |n: &BigUint| n.to_u64();
+        let range = |cnt: &BigUint, floor: &Option<BigUint>| (cnt - floor.as_ref().unwrap_or(cnt)).to_u64()?.checked_add(1);
+
+        match self.mode {
+            Mode::Passphrase { .. } => Some(1),
+            Mode::Groups => Some(self.groups.len() as u64),
+            _ => {
+                let letters = if self.mode == Mode::Chars && self.shuffle {
+                    (cnt(&self.min_upper)?.checked_add(1)?).checked_mul(cnt(&self.min_lower)?.checked_add(1)?)?
+                } else {
+                    1
+                };
+                range(&self.sbl_cnt, &self.sbl_floor)?.checked_mul(range(&self.num_cnt, &self.num_floor)?)?.checked_mul(letters)
+            }
+        }
+
+    }
+
+
+    /// `combinations` however large it is, the counts must fit the bits of `combination_bits`
+    #[inline]
+    fn combinations_unbounded(&self) -> BigUint {
+
+        if let Mode::Passphrase { word_cnt, .. } = self.mode {
+            return BigUint::from(_WORDS(self).len()).pow(word_cnt as u32);
+        }
+        // Which positions every group takes, times the draws of the groups
+        if self.mode == Mode::Groups {
+            let mut left = self.groups.iter().map(|(_, cnt)| cnt).sum::<usize>();
+            return self.groups.iter().fold(BigUint::one(), |acc, (alphabet, cnt)| {
+                let positions = _BINOMIAL(left, *cnt);
+                left -= cnt;
+                acc * positions * BigUint::from(alphabet.chars().count()).pow(*cnt as u32)
+            });
+        }
+
+        let cnt = |n: &BigUint| n.to_usize().unwrap();
+        let (l, s, n) = (cnt(&self.ltr_cnt), cnt(&self.sbl_cnt), cnt(&self.num_cnt));
+        let sbls = self.sbl_floor.as_ref().map_or(s, cnt)..=s;
+        let nums = self.num_floor.as_ref().map_or(n, cnt)..=n;
+
+        // The drawn counts can be told apart by the pools, so their numbers add up
+        sbls.flat_map(|s2| nums.clone().map(move |n2| (s2, n2)))
+            .map(|(s2, n2)| self.combinations_of(l + s - s2 + n - n2, s2, n2))
+            .sum()
+
+    }
+
+
+    /// The number of passwords with exactly `l` letters, `s` symbols and `n` numbers
+    #[inline]
+    fn combinations_of(&self, l: usize, s: usize, n: usize) -> BigUint {
+
+        let pools = _POOLS(self);
+        let size = |i: usize| BigUint::from(pools[i].1.len());
+        let cnt = |n: &BigUint| n.to_usize().unwrap();
+
+        if self.mode == Mode::Pronounceable {
+            let (consonants, vowels) = _SYLLABLE_POOLS(self);
+            let (c, v) = (BigUint::from(consonants.len()), BigUint::from(vowels.len()));
+            let (half, rest) = ((l / 2) as u32, (l - l / 2) as u32);
+            let letters = c.pow(rest) * v.pow(half) + c.pow(half) * v.pow(rest);
+
+            return _BINOMIAL(l + s + n, s + n) * _BINOMIAL(s + n, s)
+                * letters
+                * size(3).pow(s as u32)
+                * size(4).pow(n as u32);
+        }
+
+        let (u, w) = (cnt(&self.min_upper), cnt(&self.min_lower));
+
+        if !self.shuffle {
+            return size(0).pow((l - u - w) as u32) * size(1).pow(u as u32) * size(2).pow(w as u32)
+                * size(3).pow(s as u32)
+                * size(4).pow(n as u32);
+        }
+
+        // All the letter strings, but those with fewer than `u` uppercase or `w` lowercase letters,
+        // where the ones short of both were taken out twice
+        let letters = if u == 0 && w == 0 {
+            size(0).pow(l as u32)
+        } else {
+            let others = size(0) - size(1) - size(2);
+            let short = |min: usize, pool: BigUint| (0..min.min(l + 1))
+                .map(|a| _BINOMIAL(l, a) * pool.pow(a as u32) * (size(0) - &pool).pow((l - a) as u32))
+                .sum::<BigUint>();
+            let short_of_both = (0..u.min(l + 1))
+                .flat_map(|a| (0..w.min(l - a + 1)).map(move |b| (a, b)))
+                .map(|(a, b)| {
+                    _BINOMIAL(l, a) * _BINOMIAL(l - a, b)
+                        * size(1).pow(a as u32)
+                        * size(2).pow(b as u32)
+                        * others.pow((l - a - b) as u32)
+                })
+                .sum::<BigUint>();
+            size(0).pow(l as u32) + short_of_both - short(u, size(1)) - short(w, size(2))
+        };
+
+        _BINOMIAL(l + s + n, l) * _BINOMIAL(s + n, s)
+            * letters
+            * size(3).pow(s as u32)
+            * size(4).pow(n as u32)
+
+    }
+
+
+    /// Generate the password for `RandPwd`
+    ///
+    /// Use `thread_rng` unless `RandPwd` is seeded, and so do the methods generating
+    /// from `&self`, which leave the content of `RandPwd` untouched
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join();
+    /// println!("{}", r_p);
+    ///
+    /// // Every character is drawn from its own pool, none is left out
+    /// let mut r_p = RandPwd::new(2000, 1000, 1000).unwrap();
+    /// r_p.with_charset(rand_pwd::Charset::custom("ab", "-", "01")).unwrap();
+    /// r_p.set_unit(7);
+    /// r_p.join();
+    /// assert_eq!(r_p.len(), 4000);
+    /// for (ch, at_least) in [('a', 800), ('b', 800), ('-', 1000), ('0', 400), ('1', 400)] {
+    ///     assert!(r_p.val().matches(ch).count() >= at_least);
+    /// }
+    /// ```
+    /// # Panics
+    /// If `no_repeats` or `no_sequences` can't be satisfied, see `try_join`
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join(&mut self) {
+        self.try_join().unwrap()
+    }
+
+
+    /// Generate the password like `join`, or fail if `no_repeats` or `no_sequences`
+    /// is still broken after 1000 passwords were drawn
+    ///
+    /// The builder rejects the counts which can never satisfy `no_repeats`,
+    /// but the pools may be changed afterwards.
+    /// ```
+    /// use rand_pwd::{ RandPwd, Charset, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(6).numbers(4).no_repeats(true).build().unwrap();
+    /// assert!(r_p.try_join().is_ok());
+    ///
+    /// // Six `a` in ten characters always have two side by side
+    /// r_p.with_charset(Charset::custom("a", "", "1")).unwrap();
+    /// assert_eq!(r_p.try_join(), Err(PasswordError::ConstraintUnsatisfiable("no_repeats")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_join(&mut self) -> Result<(), PasswordError> {
+        match self.seed {
+            Some(seed) => self.try_join_with_rng(&mut StdRng::seed_from_u64(seed)),
+            None       => self.try_join_with_rng(&mut thread_rng()),
+        }
+    }
+
+
+    /// Generate a new password with the same configuration and return `RandPwd` for chaining,
+    /// use `val` to read the last generated password without regenerating
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// let first = r_p.regenerate().val().to_string();
+    /// assert_eq!(first, r_p.val());
+    /// assert_ne!(first, r_p.regenerate().val());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn regenerate(&mut self) -> &mut Self {
+        self.join();
+        self
+    }
+
+
+    /// Generate the password for `RandPwd` and return a borrow of it,
+    /// no copy of the password is made
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// assert_eq!(r_p.join_ref().len(), 15);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_ref(&mut self) -> &str {
+        self.join();
+        self.val()
+    }
+
+
+    /// Generate a password of `length` characters with the configuration of `RandPwd`,
+    /// which keeps its counts for the next passwords
+    ///
+    /// The letters take what the symbols and numbers leave. If `length` is shorter than
+    /// `sbl + num` the letters are dropped and the symbols and numbers are cut down in proportion,
+    /// rounded in favor of the numbers, then `min_upper`, `min_lower` and the floors of the ranges
+    /// shrink to what is left. A passphrase ignores `length`, the positions of a pattern
+    /// are dropped for the shuffle. The counts of `from_groups` can't be cut down this way,
+    /// so it fails with `"join_len"`.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::builder().letters(12).symbols(4).numbers(4).min_upper(2).build().unwrap();
+    /// r_p.join_len(10).unwrap();
+    /// assert_eq!(r_p.category_counts(), (2, 4, 4));
+    ///
+    /// // 6 slots for 4 symbols and 4 numbers
+    /// r_p.join_len(6).unwrap();
+    /// assert_eq!(r_p.category_counts(), (0, 3, 3));
+    ///
+    /// r_p.join();
+    /// assert_eq!(r_p.len(), 20);
+    ///
+    /// let mut r_p = RandPwd::from_groups(vec![("abc".into(), 4u8.into()), ("012".into(), 4u8.into())], 0u8.into(), String::new()).unwrap();
+    /// assert_eq!(r_p.join_len(3), Err(PasswordError::ConstraintUnsatisfiable("join_len")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_len<T: ToBigUint>(&mut self, length: T) -> Result<(), PasswordError> {
+
+        let length = length.to_biguint().ok_or(PasswordError::InvalidCount("len"))?;
+        if self.mode == Mode::Groups {
+            return Err(PasswordError::ConstraintUnsatisfiable("join_len"));
+        }
+
+        let mut r_p = self.clone_config();
+        let others = &r_p.sbl_cnt + &r_p.num_cnt;
+        if length >= others {
+            r_p.ltr_cnt = length - others;
+        } else {
+            let sbl_cnt = &r_p.sbl_cnt * &length / &others;
+            r_p.num_cnt = &length - &sbl_cnt;
+            r_p.sbl_cnt = sbl_cnt;
+            r_p.ltr_cnt = BigUint::zero();
+        }
+        r_p.min_upper = r_p.min_upper.clone().min(r_p.ltr_cnt.clone());
+        r_p.min_lower = r_p.min_lower.clone().min(&r_p.ltr_cnt - &r_p.min_upper);
+        r_p.sbl_floor = r_p.sbl_floor.take().map(|floor| floor.min(r_p.sbl_cnt.clone()));
+        r_p.num_floor = r_p.num_floor.take().map(|floor| floor.min(r_p.num_cnt.clone()));
+        if r_p.pattern.take().is_some() {
+            r_p.shuffle = true;
+        }
+
+        _JOIN_INTO(&r_p, &mut r_p.std_rng(), &mut self.content)
+
+    }
+
+
+    /// Generate passwords until one differs from `previous`, or fail after `max_tries`
+    /// like `join_matching`, for a new password which must not be the old one
+    ///
+    /// Every password is generated the way `join` does and compared with `previous`,
+    /// in constant time with the `subtle` feature, see `ct_eq`. If `combinations` is one and `previous` is that password,
+    /// every try gives it again and it always fails.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::from_alphabet("ab", 2).unwrap();
+    /// for _ in 0..20 {
+    ///     r_p.join_different("ab", 100).unwrap();
+    ///     assert_ne!(r_p.val(), "ab");
+    /// }
+    ///
+    /// // "aaaa" is the only password there is
+    /// let mut r_p = RandPwd::from_alphabet("a", 4).unwrap();
+    /// assert_eq!(r_p.join_different("aaaa", 10), Err(PasswordError::ConstraintUnsatisfiable("different")));
+    /// r_p.join_different("aaa", 10).unwrap();
+    /// assert_eq!(r_p.val(), "aaaa");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_different(&mut self, previous: &str, max_tries: usize) -> Result<(), PasswordError> {
+        match self.join_matching(|pwd| !_CT_EQ(pwd, previous), max_tries) {
+            Err(PasswordError::ConstraintUnsatisfiable("predicate")) => Err(PasswordError::ConstraintUnsatisfiable("different")),
+            res => res,
+        }
+    }
+
+
+    /// Generate passwords until one passes `predicate`, for the rules the builder can't express
+    ///
+    /// The passwords are drawn one after the other from the generator `join` would use,
+    /// so a seeded `RandPwd` still moves on. After `max_tries` rejected ones it fails
+    /// and the content is left empty.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// use std::collections::HashSet;
+    /// let mut r_p = RandPwd::with_seed(10, 2, 3, 7).unwrap();
+    /// let distinct_symbols = |pwd: &str| pwd.chars().filter(char::is_ascii_punctuation).collect::<HashSet<_>>().len() == 2;
+    /// r_p.join_matching(distinct_symbols, 100).unwrap();
+    /// assert!(distinct_symbols(r_p.val()));
+    ///
+    /// assert_eq!(r_p.join_matching(|pwd| pwd.is_empty(), 10), Err(PasswordError::ConstraintUnsatisfiable("predicate")));
+    /// assert!(r_p.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_matching<P: Fn(&str) -> bool>(&mut self, predicate: P, max_tries: usize) -> Result<(), PasswordError> {
+
+        let mut rng = self.std_rng();
+        let mut PWD = mem::take(&mut self.content);
+
+        for _ in 0..max_tries {
+            if let Err(err) = _JOIN_INTO(self, &mut rng, &mut PWD) {
+                self.content = PWD;
+                return Err(err);
+            }
+            if predicate(&PWD) {
+                self.content = PWD;
+                return Ok(());
+            }
+        }
+
+        _WIPE(&mut PWD);
+        self.content = PWD;
+        Err(PasswordError::ConstraintUnsatisfiable("predicate"))
+
+    }
+
+
+    /// Draw the symbols of the content again, the letters and numbers stay where they are
+    ///
+    /// The positions of the symbols are the characters of the symbol pool, and as many symbols
+    /// as the content has are drawn. If the content matches the policy the new one does too,
+    /// the draws give up with `ConstraintUnsatisfiable("reroll")` and leave the content untouched.
+    /// A seeded `RandPwd` draws the same symbols every time.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 4, 3).unwrap();
+    /// r_p.join();
+    /// let old = r_p.val().to_string();
+    /// r_p.reroll_symbols().unwrap();
+    ///
+    /// let kept = |pwd: &str| pwd.chars().map(|ch| if ch.is_ascii_punctuation() { '_' } else { ch }).collect::<String>();
+    /// assert_eq!(kept(r_p.val()), kept(&old));
+    /// assert!(r_p.matches_policy(r_p.val()));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn reroll_symbols(&mut self) -> Result<(), PasswordError> {
+        self.reroll(1)
+    }
+
+
+    /// Draw the numbers of the content again, the letters and symbols stay where they are, see `reroll_symbols`
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::with_seed(10, 4, 30, 7).unwrap();
+    /// r_p.join();
+    /// let old = r_p.val().to_string();
+    /// r_p.reroll_numbers().unwrap();
+    /// assert_ne!(r_p.val(), old);
+    /// assert_eq!(r_p.val().replace(|ch: char| ch.is_ascii_digit(), ""), old.replace(|ch: char| ch.is_ascii_digit(), ""));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn reroll_numbers(&mut self) -> Result<(), PasswordError> {
+        self.reroll(2)
+    }
+
+
+    /// Draw the letters of the content again, the symbols and numbers stay where they are, see `reroll_symbols`
+    ///
+    /// The new letters have at least `min_upper` uppercase and `min_lower` lowercase ones
+    /// and follow the case policy, the leet substitutions aren't made again.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::builder().letters(8).symbols(2).numbers(2).min_upper(3).build().unwrap();
+    /// r_p.join();
+    /// let old = r_p.val().to_string();
+    /// r_p.reroll_letters().unwrap();
+    /// assert_eq!(r_p.val().replace(char::is_alphabetic, ""), old.replace(char::is_alphabetic, ""));
+    /// assert!(r_p.val().chars().filter(char::is_ascii_uppercase).count() >= 3);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn reroll_letters(&mut self) -> Result<(), PasswordError> {
+        self.reroll(0)
+    }
+
+
+    /// Draw the characters of the category `kind` of the content again
+    #[cfg(feature = "std")]
+    #[inline]
+    fn reroll(&mut self, kind: usize) -> Result<(), PasswordError> {
+
+        let PWD = _REROLL(self, kind, &mut self.std_rng())?;
+        _WIPE(&mut self.content);
+        self.content = PWD;
+
+        Ok(())
+
+    }
+
+
+    /// Generate the password for `RandPwd` and put `separator` after every `group_size` characters,
+    /// like `XXXX-XXXX-XXXX-XXXX`
+    ///
+    /// The separators come on top of the configured length and don't add any entropy,
+    /// a group as long as the password or longer leaves it without separators.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(16, 0, 0).unwrap();
+    /// r_p.join_grouped(4, '-').unwrap();
+    /// assert_eq!(r_p.len(), 19);
+    /// assert!(r_p.val().split('-').all(|group| group.len() == 4));
+    ///
+    /// r_p.join_grouped(16, '-').unwrap();
+    /// assert!(!r_p.val().contains('-'));
+    ///
+    /// assert_eq!(r_p.join_grouped(0, '-'), Err(PasswordError::InvalidCount("group")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_grouped(&mut self, group_size: usize, separator: char) -> Result<(), PasswordError> {
+
+        if group_size == 0 {
+            return Err(PasswordError::InvalidCount("group"));
+        }
+
+        self.join();
+        let PWD = self
+            .content
+            .chars()
+            .enumerate()
+            .flat_map(|(i, ch)| if i > 0 && i % group_size == 0 { vec![separator, ch] } else { vec![ch] })
+            .collect();
+        self.clear();
+        self.content = PWD;
+
+        Ok(())
+
+    }
+
+
+    /// Generate the password for `RandPwd` and return it as UTF-8 bytes, e.g. for a KDF
+    ///
+    /// The bytes are moved out rather than copied, so the content of `RandPwd` is left empty
+    /// and the returned buffer is the only copy, wipe it with `Zeroize` when it's done.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// let bytes = r_p.join_bytes();
+    /// assert_eq!(bytes.len(), 15);
+    /// assert!(String::from_utf8(bytes).is_ok());
+    /// assert!(r_p.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_bytes(&mut self) -> Vec<u8> {
+        self.join();
+        mem::take(&mut self.content).into_bytes()
+    }
+
+
+    /// Generate the password for `RandPwd` with the given random number generator
+    ///
+    /// Every index draw and the final shuffle go through `rng`,
+    /// so a cryptographically secure generator can be chosen explicitly.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// use rand::rngs::OsRng;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join_with_rng(&mut OsRng);
+    /// assert_eq!(r_p.len(), 15);
+    /// ```
+    /// # Panics
+    /// If `no_repeats` or `no_sequences` can't be satisfied, see `try_join_with_rng`
+    #[inline]
+    pub fn join_with_rng<R: RngCore + CryptoRng>(&mut self, rng: &mut R) {
+        self.try_join_with_rng(rng).unwrap()
+    }
+
+
+    /// Generate the password like `join_with_rng`, or fail like `try_join`,
+    /// the content is left empty then
+    #[inline]
+    pub fn try_join_with_rng<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> Result<(), PasswordError> {
+        // Generate into the old content, so its allocation is reused
+        let mut PWD = mem::take(&mut self.content);
+        let result = _JOIN_INTO(self, rng, &mut PWD);
+        self.content = PWD;
+        result
+    }
+
+
+    /// Generate a password into `buf` with the configuration of `RandPwd`
+    ///
+    /// `buf` is wiped first like `clear` does, and its allocation is reused,
+    /// so recycling one buffer saves allocating a `String` for every password.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::with_seed(10, 2, 3, 7).unwrap();
+    /// let mut buf = String::with_capacity(15);
+    /// for _ in 0..3 {
+    ///     r_p.join_into(&mut buf);
+    ///     assert_eq!(buf.len(), 15);
+    /// }
+    ///
+    /// let mut other = r_p.clone();
+    /// other.join();
+    /// assert_eq!(buf, other.val());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_into(&self, buf: &mut String) {
+        _JOIN_INTO(self, &mut self.std_rng(), buf).unwrap()
+    }
+
+
+    /// Generate a password with `RandPwd` and one with `other` and return them one after the other
+    ///
+    /// Each half is drawn with its own configuration and generator, nothing is shuffled
+    /// across the boundary. The constraints only hold within each half, e.g. `no_repeats`
+    /// doesn't look at the last character of the first half and the first one of the second.
+    /// # Panics
+    /// Like `join`, if the constraints of either half can't be satisfied.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let env = RandPwd::from_alphabet("abcdefghijklmnopqrstuvwxyz", 4).unwrap();
+    /// let token = RandPwd::new(0, 0, 12).unwrap();
+    /// let pwd = env.concat(&token);
+    /// assert_eq!(pwd.len(), 16);
+    /// assert!(env.matches_policy(&pwd[..4]));
+    /// assert!(token.matches_policy(&pwd[4..]));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn concat(&self, other: &RandPwd) -> String {
+
+        let (mut PWD, mut rest) = (String::new(), String::new());
+        _JOIN_INTO(self, &mut self.std_rng(), &mut PWD).unwrap();
+        _JOIN_INTO(other, &mut other.std_rng(), &mut rest).unwrap();
+
+        PWD.reserve(rest.len());
+        PWD.push_str(&rest);
+        _WIPE(&mut rest);
+
+        PWD
+
+    }
+
+
+    /// Generate a password into `out`
+    ///
+    /// `out` wipes the password when it's dropped and no other copy is handed back.
+    /// The characters and the indexes drawn for the letters, symbols and numbers, the passwords
+    /// rejected by the constraints and the password before its check characters are wiped too,
+    /// the buffers of passphrases, pronounceable passwords and groups may still leave copies
+    /// in freed memory.
+    /// # Panics
+    /// Like `join`, if the constraints can't be satisfied.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// use zeroize::Zeroizing;
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// let mut out = Zeroizing::new(String::new());
+    /// r_p.fill_secret(&mut out);
+    /// assert_eq!(out.len(), 15);
+    /// assert!(r_p.matches_policy(&out));
+    /// assert!(r_p.is_empty());
+    /// ```
+    #[cfg(all(feature = "std", feature = "zeroize"))]
+    #[inline]
+    pub fn fill_secret(&self, out: &mut Zeroizing<String>) {
+        _JOIN_INTO(self, &mut self.std_rng(), out).unwrap()
+    }
+
+
+    /// Generate a password into an array of `N` bytes
+    ///
+    /// It fails with `ConstraintUnsatisfiable("array")` unless the password takes exactly `N` bytes
+    /// in UTF-8, which for ASCII pools is the length. The password is drawn into a buffer
+    /// which is wiped once it's copied into the array.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::new(10, 2, 4).unwrap();
+    /// let pwd: [u8; 16] = r_p.join_array().unwrap();
+    /// assert!(r_p.matches_policy(std::str::from_utf8(&pwd).unwrap()));
+    ///
+    /// assert_eq!(r_p.join_array::<8>(), Err(PasswordError::ConstraintUnsatisfiable("array")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_array<const N: usize>(&self) -> Result<[u8; N], PasswordError> {
+
+        // Every character takes a byte at least, a longer password is never drawn
+        let unfit = PasswordError::ConstraintUnsatisfiable("array");
+        let length = self.length() + self.checksum.map_or(0, CheckKind::len) + self.prefix.len() + self.suffix.len();
+        if self.mode == Mode::Chars && length > BigUint::from(N) {
+            return Err(unfit);
+        }
+
+        let mut PWD = String::with_capacity(N);
+        _JOIN_INTO(self, &mut self.std_rng(), &mut PWD)?;
+
+        let mut out = [0u8; N];
+        let fits = PWD.len() == N;
+        if fits {
+            out.copy_from_slice(PWD.as_bytes());
+        }
+        _WIPE(&mut PWD);
+
+        if fits { Ok(out) } else { Err(unfit) }
+
+    }
+
+
+    /// Generate `ltr + sbl + num` random bytes and store them hex encoded
+    ///
+    /// The categories are bypassed, so only the total of the counts matters.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 4).unwrap();
+    /// r_p.join_hex();
+    /// assert_eq!(r_p.len(), 32);
+    /// assert!(r_p.val().chars().all(|ch| ch.is_ascii_hexdigit()));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_hex(&mut self) {
+        let bytes = _BYTES(self, &mut self.std_rng());
+        self.clear();
+        self.content = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    }
+
+
+    /// Generate `ltr + sbl + num` random bytes and store them base64 encoded
+    ///
+    /// The categories are bypassed, so only the total of the counts matters.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// r_p.join_base64();
+    /// assert_eq!(r_p.len(), 20);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_base64(&mut self) {
+        let bytes = _BYTES(self, &mut self.std_rng());
+        self.clear();
+        self.content = base64::encode(&bytes);
+    }
+
+
+    /// Generate the password for `RandPwd`, put it on the clipboard and return it
+    ///
+    /// The clipboard is the one of `arboard`, on Windows, macOS, and Linux and the BSDs under X11
+    /// or a Wayland compositor with the data control protocol. On Linux the password is served
+    /// by the process, so it's gone when the process exits unless a clipboard manager took it.
+    /// `PasswordError::Clipboard` is returned when there's no clipboard, e.g. without a display,
+    /// the password is generated all the same.
+    ///
+    /// The clipboard, and a clipboard manager and its history, may keep the password
+    /// long after `RandPwd` is dropped, and any program can read it there, `zeroize` can't reach it.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::default();
+    /// match r_p.show_to_clipboard() {
+    ///     Ok(pwd) => assert_eq!(pwd.len(), 16),
+    ///     Err(err) => assert!(matches!(err, PasswordError::Clipboard(_))),
+    /// }
+    /// assert_eq!(r_p.len(), 16);
+    /// ```
+    #[cfg(feature = "clipboard")]
+    #[inline]
+    pub fn show_to_clipboard(&mut self) -> Result<&str, PasswordError> {
+
+        self.join();
+        let failed = |err: arboard::Error| PasswordError::Clipboard(err.to_string());
+        arboard::Clipboard::new().map_err(failed)?.set_text(self.content.as_str()).map_err(failed)?;
+
+        Ok(self.val())
+
+    }
+
+
+    /// Return the chunks of a password without holding all of it in memory
+    ///
+    /// A chunk has at most `UNIT` characters, see `set_unit`, and is made of whole characters,
+    /// the prefix and the suffix are chunks of their own.
+    /// `no_repeats`, `no_sequences`, `no_edge_symbols`, `all_distinct`, a pattern and a checksum
+    /// need the whole password, so they are not supported.
+    ///
+    /// The chunks can be written by any writer, an async one included, see `generate_stream`.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let mut r_p = RandPwd::new(9_000, 500, 500).unwrap();
+    /// r_p.with_letters("äöü").unwrap();
+    /// r_p.set_unit(4096);
+    /// let chunks = r_p.join_chunks().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(chunks.iter().map(|chunk| chunk.chars().count()).collect::<Vec<_>>(), vec![4096, 4096, 1808]);
+    /// assert!(r_p.matches_policy(&chunks.concat()));
+    ///
+    /// let r_p = RandPwd::builder().letters(8).no_repeats(true).build().unwrap();
+    /// assert_eq!(r_p.join_chunks().err(), Some(PasswordError::ConstraintUnsatisfiable("no_repeats")));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_chunks(&self) -> Result<impl Iterator<Item = String> + '_, PasswordError> {
+
+        let unsupported = [
+            (self.no_repeats, "no_repeats"),
+            (self.no_sequences, "no_sequences"),
+            (self.no_edge_symbols, "no_edge_symbols"),
+            (self.all_distinct, "all_distinct"),
+            (self.pattern.is_some(), "pattern"),
+            (self.checksum.is_some(), "checksum"),
+        ];
+        if let Some((_, name)) = unsupported.iter().find(|(on, _)| *on) {
+            return Err(PasswordError::ConstraintUnsatisfiable(name));
+        }
+
+        _CHUNKS(self, self.std_rng())
+
+    }
+
+
+    /// Write a password into `w` chunk by chunk without holding all of it in memory
+    ///
+    /// The chunks are the ones of `join_chunks`, its errors are of kind `InvalidInput`.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let mut r_p = RandPwd::new(100_000, 2_000, 3_000).unwrap();
+    /// r_p.set_unit(4096);
+    /// let mut out = Vec::new();
+    /// r_p.join_to_writer(&mut out).unwrap();
+    ///
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert_eq!(out.len(), 105_000);
+    /// assert_eq!(out.matches(|ch: char| ch.is_ascii_digit()).count(), 3_000);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn join_to_writer<W: Write>(&self, w: &mut W) -> io::Result<()> {
+
+        let chunks = self.join_chunks().map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        for chunk in chunks {
+            w.write_all(chunk.as_bytes())?;
+        }
+
+        Ok(())
+
+    }
+
+
+    /// Write a password into the async writer `w` chunk by chunk, like `join_to_writer`
+    ///
+    /// Every chunk of `join_chunks` is written with `AsyncWriteExt::write_all` before the next one
+    /// is drawn, its errors are of kind `InvalidInput`.
+    /// ```
+    /// # #[cfg(feature = "tokio")] {
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(100_000, 2_000, 3_000).unwrap();
+    /// let mut out = Vec::new();
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// runtime.block_on(r_p.generate_stream(&mut out)).unwrap();
+    ///
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert_eq!(out.len(), 105_000);
+    /// assert_eq!(out.matches(|ch: char| ch.is_ascii_digit()).count(), 3_000);
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[inline]
+    pub async fn generate_stream<W: AsyncWrite + Unpin>(&self, w: &mut W) -> io::Result<()> {
+
+        let chunks = self.join_chunks().map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        for chunk in chunks {
+            w.write_all(chunk.as_bytes()).await?;
+        }
+
+        Ok(())
+
+    }
+
+
+    /// Write `count` passwords to the file at `path`, one per line, the file is created
+    /// or truncated
+    ///
+    /// The passwords are generated in parallel like `generate_many`,
+    /// but in batches that are written and wiped one by one, so they are never all in memory.
+    /// A seeded `RandPwd` writes the passwords of `generate_many`. A password that
+    /// can't be generated is an error of kind `InvalidInput`, the lines written so far are kept.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let path = std::env::temp_dir().join("rand_pwd_write_many.txt");
+    /// let r_p = RandPwd::with_seed(10, 2, 3, 7).unwrap();
+    /// r_p.write_many_to_file(1000, &path).unwrap();
+    ///
+    /// let written = std::fs::read_to_string(&path).unwrap();
+    /// assert_eq!(written.lines().count(), 1000);
+    /// assert!(written.lines().all(|pwd| r_p.matches_policy(pwd)));
+    /// assert_eq!(written.lines().collect::<Vec<_>>(), r_p.generate_many(1000));
+    /// std::fs::remove_file(&path).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn write_many_to_file(&self, count: usize, path: &Path) -> io::Result<()> {
+
+        let mut w = BufWriter::new(File::create(path)?);
+        let mut rng = self.std_rng();
+        let mut left = count;
+
+        while left > 0 {
+            let batch = left.min(BATCH);
+            let mut pwds = self.join_many(batch, &mut rng).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+            let res = pwds.iter().try_for_each(|pwd| writeln!(w, "{}", pwd));
+            pwds.iter_mut().for_each(_WIPE);
+            res?;
+            left -= batch;
+        }
+
+        w.flush()
+
+    }
+
+
+    /// Return an endless iterator of passwords generated with the configuration of `RandPwd`
+    ///
+    /// Every password is drawn and shuffled on its own. A seeded `RandPwd` yields
+    /// the same sequence each time, starting with the password `join` would produce.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// let pwds = r_p.iter().take(100).collect::<Vec<_>>();
+    /// assert_eq!(pwds.len(), 100);
+    /// assert!(pwds.iter().all(|pwd| pwd.len() == 15));
+    /// assert!(r_p.is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = String> + '_ {
+        let mut rng = self.std_rng();
+        std::iter::repeat_with(move || _JOIN(self, &mut rng).unwrap())
+    }
+
+
+    /// Generate passwords like `iter`, every one comes with the entropy of the configuration
+    /// ```
+    /// use rand_pwd::{ RandPwd, Strength };
+    /// let r_p = RandPwd::new(4, 1, 1).unwrap();
+    /// let candidate = r_p.iter_detailed().next().unwrap();
+    /// assert_eq!(candidate.password.len(), 6);
+    /// assert_eq!(candidate.entropy_bits, r_p.entropy_bits());
+    ///
+    /// let strong = RandPwd::default().iter_detailed().filter(|c| c.strength() == Strength::Strong).take(3).count();
+    /// assert_eq!(strong, 3);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn iter_detailed(&self) -> impl Iterator<Item = Candidate> + '_ {
+        let entropy_bits = self.entropy_bits();
+        self.iter().map(move |password| Candidate { password, entropy_bits })
+    }
+
+
+    /// Generate `count` passwords in parallel with the configuration of `RandPwd`
+    ///
+    /// Every password has its own generator, they are all seeded up front,
+    /// so a seeded `RandPwd` always returns the same passwords.
+    /// ```
+    /// use rand_pwd::RandPwd;
+    /// let r_p = RandPwd::new(10, 2, 3).unwrap();
+    /// let pwds = r_p.generate_many(1000);
+    /// assert_eq!(pwds.len(), 1000);
+    /// assert!(pwds.iter().all(|pwd| pwd.len() == 15));
+    ///
+    /// let r_p = RandPwd::with_seed(10, 2, 3, 7).unwrap();
+    /// assert_eq!(r_p.generate_many(10), r_p.generate_many(10));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn generate_many(&self, count: usize) -> Vec<String> {
+        self.join_many(count, &mut self.std_rng()).unwrap()
+    }
+
+
+    /// Generate `count` passwords, each from a generator of its own seeded by `rng`,
+    /// in parallel unless `set_parallel` said otherwise
+    #[cfg(feature = "std")]
+    #[inline]
+    fn join_many<R: Rng>(&self, count: usize, rng: &mut R) -> Result<Vec<String>, PasswordError> {
+
+        let rngs = (0..count).map(|_| StdRng::from_rng(&mut *rng).unwrap()).collect::<Vec<_>>();
+
+        if self.parallel {
+            rngs.into_par_iter().map(|mut rng| _JOIN(self, &mut rng)).collect()
+        } else {
+            rngs.into_iter().map(|mut rng| _JOIN(self, &mut rng)).collect()
+        }
+
+    }
+
+
+    /// Generate `count` distinct passwords with the configuration of `RandPwd`
+    ///
+    /// The passwords come from `iter` in order, a duplicate is wiped and drawn again.
+    /// If `combinations` is smaller than `count` it fails right away, otherwise it gives up
+    /// after `count` times 1000 draws, for the passwords that `no_repeats` or `no_sequences`
+    /// reject, or that `set_leetify` turns into the same one, are still counted there.
+    /// ```
+    /// use rand_pwd::{ RandPwd, PasswordError };
+    /// let r_p = RandPwd::new(0, 0, 3).unwrap();
+    /// let pins = r_p.generate_unique(1000).unwrap();
+    /// assert_eq!(pins.iter().collect::<std::collections::HashSet<_>>().len(), 1000);
+    ///
+    /// assert_eq!(r_p.generate_unique(1001).unwrap_err(), PasswordError::ConstraintUnsatisfiable("unique"));
+    ///
+    /// // 2 numbers with no repeats are only 90 passwords, though `combinations` counts 100
+    /// let r_p = RandPwd::builder().numbers(2).no_repeats(true).build().unwrap();
+    /// assert_eq!(r_p.generate_unique(91).unwrap_err(), PasswordError::ConstraintUnsatisfiable("unique"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn generate_unique(&self, count: usize) -> Result<Vec<String>, PasswordError> {
+
+        // `entropy_bits` is a cheap lower bound, the exact count is only needed near the limit
+        if self.entropy_bits() <= (count as f64).log2() + 1.0 && self.combinations().is_some_and(|cnt| cnt < BigUint::from(count)) {
+            return Err(PasswordError::ConstraintUnsatisfiable("unique"));
+        }
+
+        // Every password is kept once, by its position, so none is left behind unwiped
+        let mut seen = BTreeMap::new();
+        for mut pwd in self.iter().take(count.saturating_mul(MAX_DRAWS)) {
+            if seen.len() == count {
+                break;
+            }
+            if seen.contains_key(&pwd) {
+                _WIPE(&mut pwd);
+            } else {
+                let idx = seen.len();
+                seen.insert(pwd, idx);
+            }
+        }
+
+        if seen.len() < count {
+            for (mut pwd, _) in seen {
+                _WIPE(&mut pwd);
+            }
+            return Err(PasswordError::ConstraintUnsatisfiable("unique"));
+        }
+
+        let mut pwds = vec![String::new(); count];
+        for (pwd, idx) in seen {
+            pwds[idx] = pwd;
+        }
+        Ok(pwds)
+
+    }
+
+
+    /// Return a generator seeded by the seed of `RandPwd`, or by `thread_rng` if it's unseeded
+    #[cfg(feature = "std")]
+    #[inline]
+    fn std_rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None       => StdRng::from_rng(thread_rng()).unwrap(),
+        }
     }
 
 }
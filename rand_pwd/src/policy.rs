@@ -0,0 +1,71 @@
+use crate::{ RandPwdBuilder, PasswordError };
+use crate::prelude::*;
+
+
+/// The bounds a policy sets on every kind, in the order of `KINDS`
+const KINDS: [&str; 5] = ["len", "digits", "symbols", "upper", "lower"];
+
+
+/// Turn a policy like `"len>=12;digits>=2;symbols>=1"` into a builder
+///
+/// Every clause is a kind of `KINDS`, `>=`, `<=` or `=`, and a count, `numbers` and `length`
+/// are accepted for `digits` and `len`. `upper` and `lower` only take `>=`. The length is the lowest the policy allows,
+/// raised to the 16 characters of `RandPwd::default` if its upper bound lets it.
+#[inline]
+pub(crate) fn parse(policy: &str) -> Result<RandPwdBuilder, PasswordError> {
+
+    let mut min = vec![BigUint::zero(); KINDS.len()];
+    let mut max = vec![None; KINDS.len()];
+
+    for clause in policy.split(';').map(str::trim).filter(|clause| !clause.is_empty()) {
+        let invalid = || PasswordError::Policy(clause.to_string());
+
+        let at = clause.find(|ch| "<>=".contains(ch)).ok_or_else(invalid)?;
+        let (kind, rest) = clause.split_at(at);
+        let (op, cnt) = ["<=", ">=", "="]
+            .iter()
+            .find(|op| rest.starts_with(*op))
+            .map(|op| (*op, &rest[op.len()..]))
+            .ok_or_else(invalid)?;
+
+        let kind = match kind.trim() {
+            "length"  => "len",
+            "numbers" => "digits",
+            kind      => kind,
+        };
+        let i = KINDS.iter().position(|k| *k == kind).ok_or_else(invalid)?;
+        let cnt = cnt.trim().parse::<BigUint>().map_err(|_| invalid())?;
+        if i >= 3 && op != ">=" {
+            return Err(invalid());
+        }
+
+        match op {
+            ">=" => min[i] = cnt,
+            "<=" => max[i] = Some(cnt),
+            _    => { min[i] = cnt.clone(); max[i] = Some(cnt); }
+        }
+    }
+
+    let needed = min[1..].iter().sum::<BigUint>().max(min[0].clone());
+    let length = match &max[0] {
+        Some(max) if needed > *max => return Err(PasswordError::length_too_small(max.clone(), needed)),
+        Some(max) => needed.max(BigUint::from(16u8).min(max.clone())),
+        None      => needed.max(BigUint::from(16u8)),
+    };
+
+    let mut builder = RandPwdBuilder::new()
+        .length(length)
+        .numbers(min[1].clone())
+        .symbols(min[2].clone())
+        .min_upper(min[3].clone())
+        .min_lower(min[4].clone());
+    if let Some(max) = max[1].take() {
+        builder = builder.max_numbers(max);
+    }
+    if let Some(max) = max[2].take() {
+        builder = builder.max_symbols(max);
+    }
+
+    Ok(builder)
+
+}
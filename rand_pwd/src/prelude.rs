@@ -2,23 +2,153 @@
 
 pub use heapless;
 pub use rand::prelude::*;
+pub use rand::distributions::{ Uniform, WeightedIndex };
+#[cfg(feature = "std")]
 pub use rayon::prelude::*;
 pub use typenum::{ U3, U52, };
 pub use num_bigint::{ BigUint, ToBigUint };
-pub use num_traits::{ Zero, One, ToPrimitive };
-pub use std::{
-    convert::From,
+pub use num_traits::{ Zero, One, ToPrimitive, FromPrimitive };
+#[cfg(feature = "zeroize")]
+pub use zeroize::Zeroize;
+#[cfg(all(feature = "std", feature = "zeroize"))]
+pub use zeroize::Zeroizing;
+#[cfg(feature = "subtle")]
+pub use subtle::ConstantTimeEq;
+#[cfg(feature = "std")]
+pub use std::io::{ self, Write, BufWriter };
+#[cfg(feature = "std")]
+pub use std::{ fs::File, path::Path };
+#[cfg(feature = "tokio")]
+pub use tokio::io::{ AsyncWrite, AsyncWriteExt };
+#[cfg(feature = "hibp")]
+pub use sha1::{ Sha1, Digest };
+#[cfg(not(feature = "std"))]
+pub use alloc::{
+    vec,
+    boxed::Box,
+    vec::Vec,
+    string::{ String, ToString },
+};
+pub use alloc::collections::{ BTreeMap, BTreeSet };
+pub use core::{
+    mem,
+    str::FromStr,
+    convert::{ From, TryFrom },
     ops::{ Add, SubAssign, AddAssign, },
-    fmt::{ Display, Formatter, Result, },
+    fmt::{ self, Display, Formatter, },
 };
 
-pub type StrVec = heapless::Vec<String, U52>;
+pub type StrVec = heapless::Vec<char, U52>;
 pub type CharVec = heapless::Vec<StrVec, U3>;
+pub type PoolVec = [Vec<char>; 5];
+
+
+/// Default count of characters in a chunk of `_DIV_UNIT`
+pub(crate) const DEFAULT_UNIT: usize = 4096;
+
+
+/// Most passwords `_SHUFFLED` draws before giving up on `no_repeats` and `no_sequences`
+pub(crate) const MAX_DRAWS: usize = 1000;
+
+
+/// Most passwords `write_many_to_file` holds in memory at once
+#[cfg(feature = "std")]
+pub(crate) const BATCH: usize = 1024;
+
+
+/// Most bits `RandPwd::combinations` computes, a larger count would take ages to multiply out
+pub(crate) const MAX_COMBINATION_BITS: u64 = 1 << 16;
+
+
+/// Most terms `RandPwd::combinations` adds up, for the ranges of counts and the minimums of letters
+pub(crate) const MAX_COMBINATION_TERMS: u64 = 1024;
+
 
+/// Longest password `_PWD` generates sequentially, without the chunks of `_DIV_UNIT` nor rayon
+pub(crate) const SMALL_LEN: usize = 256;
 
+
+/// Consonants and vowels of pronounceable passwords
+pub(crate) const CONSONANTS: &str = "bcdfghjklmnprstvwz";
+pub(crate) const VOWELS: &str = "aeiou";
+
+
+/// Letters and their leet equivalents
+pub(crate) const LEET: [(char, char); 4] = [('a', '@'), ('e', '3'), ('o', '0'), ('s', '$')];
+
+
+/// Characters which are hard to tell apart in many fonts
+pub(crate) const AMBIGUOUS: &str = "lIoOB018";
+
+
+#[cfg(feature = "std")]
 lazy_static! {
     /// Cached the characters set
     pub static ref DATA: CharVec = _DATA();
+    /// Cached the characters set without the ambiguous characters
+    pub static ref DATA_UNAMBIGUOUS: CharVec = _DATA_UNAMBIGUOUS();
+    /// Cached the pools of the characters set
+    pub static ref POOLS: PoolVec = _DATA_POOLS();
+    /// Cached the pools of the characters set without the ambiguous characters
+    pub static ref POOLS_UNAMBIGUOUS: PoolVec = _DATA_UNAMBIGUOUS_POOLS();
+}
+
+
+/// Cached the characters set
+#[cfg(not(feature = "std"))]
+pub static DATA: Cached<CharVec> = Cached::new(_DATA);
+/// Cached the characters set without the ambiguous characters
+#[cfg(not(feature = "std"))]
+pub static DATA_UNAMBIGUOUS: Cached<CharVec> = Cached::new(_DATA_UNAMBIGUOUS);
+/// Cached the pools of the characters set
+#[cfg(not(feature = "std"))]
+pub static POOLS: Cached<PoolVec> = Cached::new(_DATA_POOLS);
+/// Cached the pools of the characters set without the ambiguous characters
+#[cfg(not(feature = "std"))]
+pub static POOLS_UNAMBIGUOUS: Cached<PoolVec> = Cached::new(_DATA_UNAMBIGUOUS_POOLS);
+
+
+/// `lazy_static` needs `spin` without `std`, so the characters sets are cached by hand,
+/// the first access builds the set, the loser of a race drops its copy
+#[cfg(not(feature = "std"))]
+pub struct Cached<T> {
+    ptr: core::sync::atomic::AtomicPtr<T>,
+    init: fn() -> T,
+}
+
+
+#[cfg(not(feature = "std"))]
+impl<T> Cached<T> {
+
+    #[inline]
+    const fn new(init: fn() -> T) -> Self {
+        Cached { ptr: core::sync::atomic::AtomicPtr::new(core::ptr::null_mut()), init }
+    }
+
+}
+
+
+#[cfg(not(feature = "std"))]
+impl<T> core::ops::Deref for Cached<T> {
+
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        use core::sync::atomic::Ordering;
+
+        let mut ptr = self.ptr.load(Ordering::Acquire);
+        if ptr.is_null() {
+            let new = Box::into_raw(Box::new((self.init)()));
+            ptr = match self.ptr.compare_exchange(core::ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_)    => new,
+                // Safety: `new` was never shared
+                Err(old) => { drop(unsafe { Box::from_raw(new) }); old }
+            };
+        }
+        // Safety: a published set is never freed
+        unsafe { &*ptr }
+    }
+
 }
 
 
@@ -37,9 +167,9 @@ pub(crate) fn _DATA() -> CharVec {
             .into_iter()
             .map(|x| {
                 let ch = x as u8 as char;
-                if ch.is_ascii_alphabetic()  { letters.push(ch.to_string()).unwrap(); }
-                if ch.is_ascii_punctuation() { numbers.push(ch.to_string()).unwrap(); }
-                if ch.is_ascii_digit()       { symbols.push(ch.to_string()).unwrap(); }
+                if ch.is_ascii_alphabetic()  { letters.push(ch).unwrap(); }
+                if ch.is_ascii_punctuation() { symbols.push(ch).unwrap(); }
+                if ch.is_ascii_digit()       { numbers.push(ch).unwrap(); }
             })
             .collect::<()>();
 
@@ -52,6 +182,45 @@ pub(crate) fn _DATA() -> CharVec {
 }
 
 
+/// Characters set without `AMBIGUOUS`
+/// return letters, symbols, numbers in `CharVec`
+#[inline]
+pub(crate) fn _DATA_UNAMBIGUOUS() -> CharVec {
+
+    DATA
+        .iter()
+        .map(|pool| pool.iter().filter(|ch| !AMBIGUOUS.contains(**ch)).copied().collect())
+        .collect()
+
+}
+
+
+/// The pools of `Charset::pools` drawn from `data` as it is,
+/// in the order like "letters->uppercase letters->lowercase letters->symbols->numbers"
+#[inline]
+pub(crate) fn _SPLIT_POOLS(data: &'static CharVec) -> PoolVec {
+
+    let pool = |i: usize, keep: fn(&char) -> bool| data[i].iter().copied().filter(keep).collect();
+
+    [pool(0, |_| true), pool(0, char::is_ascii_uppercase), pool(0, char::is_ascii_lowercase), pool(1, |_| true), pool(2, |_| true)]
+
+}
+
+
+/// Pools of `DATA`
+#[inline]
+pub(crate) fn _DATA_POOLS() -> PoolVec {
+    _SPLIT_POOLS(&DATA)
+}
+
+
+/// Pools of `DATA_UNAMBIGUOUS`
+#[inline]
+pub(crate) fn _DATA_UNAMBIGUOUS_POOLS() -> PoolVec {
+    _SPLIT_POOLS(&DATA_UNAMBIGUOUS)
+}
+
+
 /// Count the number of a string
 #[inline]
 pub(crate) fn _CNT<T: AsRef<str>>(content: T) -> (usize, usize, usize) {
@@ -76,23 +245,36 @@ pub(crate) fn _CNT<T: AsRef<str>>(content: T) -> (usize, usize, usize) {
 
 }
 
-/// Generate n random numbers, each one is up to cnt
+/// Generate n random numbers, each one is up to cnt, drawn from `rng`
+///
+/// `Uniform` rejects the draws that would favor the small numbers, so there's no modulo bias.
+/// `n` is at most a chunk of `_DIV_UNIT`, so it always fits in a `usize`.
 #[inline]
-pub(crate) fn _RAND_IDX(n: impl ToBigUint, cnt: usize) -> Vec<usize> {
+pub(crate) fn _RAND_IDX<R: Rng>(n: usize, cnt: usize, rng: &mut R) -> Vec<usize> {
+
+    if n == 0 { return Vec::new(); }
+
+    rng.sample_iter(Uniform::new(0, cnt)).take(n).collect()
+
+}
 
-    let mut n = n.to_biguint().unwrap();
-    let mut idxs = Vec::with_capacity(n.to_usize().unwrap());
 
-    while !n.is_zero() {
-        idxs.push(thread_rng().gen_range(0, cnt));
-        n -= BigUint::one();
+/// Generate n random indexes into `pool` like `_RAND_IDX`,
+/// with the weights of the `Charset` of `r_p` if it has any
+#[inline]
+pub(crate) fn _WEIGHTED_IDX<R: Rng>(r_p: &RandPwd, n: usize, pool: &[char], rng: &mut R) -> Vec<usize> {
+
+    if n == 0 || r_p.charset.weights.is_empty() {
+        return _RAND_IDX(n, pool.len(), rng);
     }
 
-    idxs
+    // `pools` dropped the characters weighing zero, so the weights can't sum up to zero
+    let dist = WeightedIndex::new(pool.iter().map(|ch| r_p.charset.weight(*ch))).unwrap();
+    rng.sample_iter(&dist).take(n).collect()
 
 }
 
-/// Resolve large numbers into smaller numbers
+/// Resolve large numbers into smaller numbers, none of them is zero
 #[inline]
 pub(crate) fn _DIV_UNIT<T>(unit: usize, n: &T) -> Vec<usize>
     where T: Clone + ToBigUint + SubAssign + PartialOrd
@@ -105,7 +287,9 @@ pub(crate) fn _DIV_UNIT<T>(unit: usize, n: &T) -> Vec<usize>
 
     loop {
         if n < UNIT {
-            ret.push(n.to_usize().unwrap());
+            if !n.is_zero() {
+                ret.push(n.to_usize().unwrap());
+            }
             break;
         } else {
             n -= UNIT.clone();
@@ -117,44 +301,842 @@ pub(crate) fn _DIV_UNIT<T>(unit: usize, n: &T) -> Vec<usize>
 
 }
 
-use crate::RandPwd;
+use crate::{ RandPwd, PasswordError, Mode, CasePolicy, CheckKind };
+use crate::wordlist::WORDLIST;
+
+/// The count and the characters pool of every category, see `Charset::pools`
+#[inline]
+pub(crate) fn _POOLS(r_p: &RandPwd) -> Vec<(BigUint, Vec<char>)> {
+    r_p.charset.pools(r_p)
+}
+
+
+/// `_POOLS` with the counts of symbols and numbers drawn from their ranges,
+/// the letters take what they leave
+#[inline]
+pub(crate) fn _DRAW_POOLS<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Vec<(BigUint, Vec<char>)> {
+
+    let mut pools = _POOLS(r_p);
+
+    for (i, floor) in [(3, &r_p.sbl_floor), (4, &r_p.num_floor)].iter() {
+        if let Some(floor) = floor {
+            let drawn = BigUint::from(rng.gen_range(floor.to_usize().unwrap(), pools[*i].0.to_usize().unwrap() + 1));
+            let left = mem::replace(&mut pools[*i].0, drawn.clone()) - drawn;
+            pools[0].0 += left;
+        }
+    }
+
+    pools
+
+}
+
+
+/// The characters of every pool of `_POOLS`
+#[inline]
+pub(crate) fn _POOL_SETS(r_p: &RandPwd) -> Vec<BTreeSet<char>> {
+    _POOLS(r_p)
+        .iter()
+        .enumerate()
+        .map(|(i, (_, pool))| {
+            let chars = pool.iter().copied();
+            // The letters are in the case `_CASE` gives them
+            match r_p.case {
+                CasePolicy::Alternating if i < 3 => chars.flat_map(|ch| vec![r_p.case.map(ch, 0), r_p.case.map(ch, 1)]).collect(),
+                _ if i < 3 => chars.map(|ch| r_p.case.map(ch, 0)).collect(),
+                _ => chars.collect(),
+            }
+        })
+        .collect()
+}
+
+
+/// Count the letters, symbols and numbers of `content` by the pools of `_POOL_SETS`,
+/// in the order of letters, symbols and numbers, the characters in none of them are not counted
+#[inline]
+pub(crate) fn _CATEGORY_COUNTS(sets: &[BTreeSet<char>], content: &str) -> (usize, usize, usize) {
+    content.chars().fold((0, 0, 0), |(l, s, n), ch| {
+        if sets[0].contains(&ch)      { (l + 1, s, n) }
+        else if sets[3].contains(&ch) { (l, s + 1, n) }
+        else if sets[4].contains(&ch) { (l, s, n + 1) }
+        else                          { (l, s, n) }
+    })
+}
+
+
+/// Split `s` into the characters of a pool
+#[inline]
+pub(crate) fn _CHARS(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+
+/// Split the inclusive code point ranges into their characters, the surrogates
+/// and whatever is beyond `char::MAX` are skipped
+#[inline]
+pub(crate) fn _RANGES(ranges: &[(u32, u32)]) -> Vec<char> {
+    ranges.iter().flat_map(|&(lo, hi)| (lo..=hi).filter_map(core::char::from_u32)).collect()
+}
+
 
-/// Generate random password but in the order like "letters->symbols->numbers"
+/// Generate random password but in the order of `_POOLS`
+///
+/// Every chunk of `_DIV_UNIT` draws its indexes from its own generator, seeded from `rng`
+/// in the order of the chunks, so the chunks are drawn in parallel when `parallel` is set
+/// and a seeded `rng` gives the same password either way. Up to `SMALL_LEN` characters
+/// the indexes are drawn from `rng` itself, sequentially.
+///
+/// The password is written into one allocation as large as its widest characters need,
+/// the indexes and the chunks on the way are wiped with `_WIPE_VEC` and `_WIPE`.
 #[inline]
-pub(crate) fn _PWD(r_p: &RandPwd) -> String {
+pub(crate) fn _PWD<R: Rng>(r_p: &RandPwd, rng: &mut R) -> String {
     // TODO: - Improve readability
 
     let unit = r_p._UNIT;
-    let data = &DATA;
+    let pools = _DRAW_POOLS(r_p, rng);
+    let width = |data: &[char]| data.iter().map(|ch| ch.len_utf8()).max().unwrap_or(0);
+    let mut PWD = String::with_capacity(pools.iter().map(|(cnt, data)| cnt.to_usize().unwrap() * width(data)).sum());
+
+    if (&r_p.ltr_cnt + &r_p.sbl_cnt + &r_p.num_cnt) <= BigUint::from(SMALL_LEN) {
+        for (i, (cnt, data)) in pools.iter().enumerate() {
+            let mut idxs = _WEIGHTED_IDX(r_p, cnt.to_usize().unwrap(), data, rng);
+            let mut distinct = _DISTINCT_IDX(r_p, i, data.len(), rng);
+            idxs.iter_mut().zip(&distinct).for_each(|(idx, distinct)| *idx = *distinct);
+            PWD.extend(idxs.iter().map(|idx| data[*idx]));
+            _WIPE_VEC(&mut idxs);
+            _WIPE_VEC(&mut distinct);
+        }
+        return PWD;
+    }
+
+    for (i, (bignum, data)) in pools.iter().enumerate() {
+        let mut distinct = _DISTINCT_IDX(r_p, i, data.len(), rng);
+        let units = _SPAN!("div_unit", _DIV_UNIT(unit, bignum))
+            .into_iter()
+            .map(|cnt| (cnt, StdRng::from_rng(&mut *rng).unwrap()))
+            .collect::<Vec<_>>();
+
+        let draw = |(cnt, mut chunk_rng): (usize, StdRng)| _SPAN!("chunk", {
+            let mut idxs = _WEIGHTED_IDX(r_p, cnt, data, &mut chunk_rng);
+            let mut chunk = String::with_capacity(cnt * width(data));
+            chunk.extend(idxs.iter().map(|idx| data[*idx]));
+            _WIPE_VEC(&mut idxs);
+            chunk
+        });
+
+        // The collect of an indexed parallel iterator keeps the order of the chunks
+        #[cfg(feature = "std")]
+        let mut chunks = if r_p.parallel {
+            units.into_par_iter().map(draw).collect::<Vec<_>>()
+        } else {
+            units.into_iter().map(draw).collect()
+        };
+        #[cfg(not(feature = "std"))]
+        let mut chunks = units.into_iter().map(draw).collect::<Vec<_>>();
+
+        // The distinct characters take the first positions of the pool
+        PWD.extend(distinct.iter().map(|idx| data[*idx]));
+        PWD.extend(chunks.iter().flat_map(|chunk| chunk.chars()).skip(distinct.len()));
+        chunks.iter_mut().for_each(_WIPE);
+        _WIPE_VEC(&mut distinct);
+    }
+
+    PWD
+
+}
+
+
+/// The category of a position of a pattern, in the order of letters, symbols and numbers
+#[inline]
+pub(crate) fn _PATTERN_KIND(ch: char) -> usize {
+    match ch {
+        'L' => 0,
+        'S' => 1,
+        _   => 2,
+    }
+}
+
+
+/// Draw the characters of every pool and put them at the positions of their category in `pattern`,
+/// the letters of the three letter pools are shuffled among themselves
+#[inline]
+pub(crate) fn _PATTERNED<R: Rng>(r_p: &RandPwd, pattern: &str, rng: &mut R) -> Vec<char> {
+
+    let pools = _POOLS(r_p);
+    let mut drawn = pools
+        .iter()
+        .enumerate()
+        .map(|(i, (cnt, pool))| {
+            let mut idxs = _WEIGHTED_IDX(r_p, cnt.to_usize().unwrap(), pool, rng);
+            idxs.iter_mut().zip(_DISTINCT_IDX(r_p, i, pool.len(), rng)).for_each(|(idx, distinct)| *idx = distinct);
+            idxs.into_iter().map(|idx| pool[idx]).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut letters = drawn[..3].concat();
+    letters.shuffle(rng);
+    let mut kinds = [letters.into_iter(), mem::take(&mut drawn[3]).into_iter(), mem::take(&mut drawn[4]).into_iter()];
+
+    pattern
+        .chars()
+        .map(|ch| kinds[_PATTERN_KIND(ch)].next().unwrap())
+        .collect()
+
+}
+
+
+/// Distinct indexes into the pool `i` of `_POOLS`, which has `size` characters, as many as
+/// `min_distinct_symbols` or `min_distinct_numbers` asks, they stand in for the first draws of the pool
+///
+/// They are drawn uniformly without replacement, whatever the weights.
+#[inline]
+pub(crate) fn _DISTINCT_IDX<R: Rng>(r_p: &RandPwd, i: usize, size: usize, rng: &mut R) -> Vec<usize> {
+
+    let n = match i {
+        3 => r_p.min_distinct_sbl,
+        4 => r_p.min_distinct_num,
+        _ => 0,
+    };
+    // Nothing is drawn without a requirement, so the seeded passwords stay the same
+    if n == 0 {
+        return Vec::new();
+    }
+
+    rand::seq::index::sample(rng, size, n.min(size)).into_vec()
+
+}
+
+
+/// Swap the characters forward until no two adjacent ones are identical,
+/// return false if the tail can't be broken up
+#[inline]
+pub(crate) fn _BREAK_REPEATS(pwd: &mut [char]) -> bool {
+
+    for i in 1..pwd.len() {
+        if pwd[i] == pwd[i - 1] {
+            match (i + 1..pwd.len()).find(|&j| pwd[j] != pwd[i]) {
+                Some(j) => pwd.swap(i, j),
+                None    => return false,
+            }
+        }
+    }
+
+    true
+
+}
+
+
+/// Swap the symbols on the first and the last positions with characters between them
+/// which aren't symbols, return false if there aren't enough
+#[inline]
+pub(crate) fn _CLEAR_EDGES(pwd: &mut [char], symbols: &BTreeSet<char>) -> bool {
+
+    let len = pwd.len();
+    if len == 0 { return true; }
+
+    for edge in [0, len - 1] {
+        if symbols.contains(&pwd[edge]) {
+            match (1..len.saturating_sub(1)).find(|&j| !symbols.contains(&pwd[j])) {
+                Some(j) => pwd.swap(edge, j),
+                None    => return false,
+            }
+        }
+    }
+
+    true
+
+}
+
+
+/// Whether `pwd[i - 2..=i]` is an ascending or descending run of code points like "abc" or "321"
+#[inline]
+pub(crate) fn _IS_SEQUENCE(pwd: &[char], i: usize) -> bool {
+    let (a, b, c) = (pwd[i - 2] as i64, pwd[i - 1] as i64, pwd[i] as i64);
+    (b - a).abs() == 1 && c - b == b - a
+}
+
+
+/// Swap the ends of the sequences of three with random positions until none is left,
+/// return false if it takes more than `4 * len` swaps
+#[inline]
+pub(crate) fn _BREAK_SEQUENCES<R: Rng>(pwd: &mut [char], rng: &mut R) -> bool {
+
+    let mut budget = 4 * pwd.len();
+    let mut i = 2;
+
+    while i < pwd.len() {
+        if _IS_SEQUENCE(pwd, i) {
+            if budget == 0 { return false; }
+            budget -= 1;
+
+            let j = rng.gen_range(0, pwd.len());
+            pwd.swap(i, j);
+            // Only the runs through `i` and `j` could have changed
+            i = i.min(j).max(2);
+        } else {
+            i += 1;
+        }
+    }
+
+    true
+
+}
+
+
+/// Whether a password without adjacent repeats can be drawn at all,
+/// a category whose pool has only one character forces its count on that character,
+/// which can't exceed half of the length rounded up
+#[inline]
+pub(crate) fn _CAN_NO_REPEATS(r_p: &RandPwd) -> bool {
+
+    let pools = _POOLS(r_p);
+    let len = pools.iter().map(|(cnt, _)| cnt).sum::<BigUint>();
+    let mut forced = BTreeMap::new();
+
+    pools
+        .iter()
+        .filter(|(cnt, pool)| !cnt.is_zero() && pool.len() == 1)
+        .for_each(|(cnt, pool)| *forced.entry(pool[0]).or_insert_with(BigUint::zero) += cnt);
+
+    forced.values().all(|cnt| *cnt <= (&len + BigUint::one()) / 2u8)
+
+}
+
+
+/// The characters of the pool `i` of `_POOLS` by what tells them apart for `all_distinct`,
+/// the letters are compared without their case once a case policy maps them
+#[inline]
+pub(crate) fn _DISTINCT_KEYS(r_p: &RandPwd, i: usize, pool: &[char]) -> BTreeMap<char, char> {
+    pool.iter()
+        .map(|ch| {
+            let key = if i < 3 && r_p.case != CasePolicy::AsIs { CasePolicy::Lower.map(*ch, 0) } else { *ch };
+            (key, *ch)
+        })
+        .collect()
+}
+
+
+/// Whether every pool has enough distinct characters for `all_distinct`,
+/// the letters of the three letter pools are drawn from the first one
+#[inline]
+pub(crate) fn _CAN_ALL_DISTINCT(r_p: &RandPwd) -> bool {
+
+    let pools = _POOLS(r_p);
+    let keys = |i: usize| BigUint::from(_DISTINCT_KEYS(r_p, i, &pools[i].1).len());
+
+    &pools[0].0 + &pools[1].0 + &pools[2].0 <= keys(0) && (1..5).all(|i| pools[i].0 <= keys(i))
 
-    vec![(&r_p.ltr_cnt, &data[0]),
-         (&r_p.sbl_cnt, &data[1]),
-         (&r_p.num_cnt, &data[2]),]
+}
+
+
+/// Draw the pools of `_DRAW_POOLS` without replacement across all of them,
+/// return none if a pool runs out
+///
+/// The guaranteed uppercase and lowercase letters are drawn first, so the letters drawn
+/// for the rest can't take what they need. The weights are not taken into account.
+#[inline]
+pub(crate) fn _ALL_DISTINCT<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Option<Vec<char>> {
+
+    let pools = _DRAW_POOLS(r_p, rng);
+    let mut used = BTreeSet::new();
+    let mut PWD = Vec::new();
+
+    for &i in [1, 2, 0, 3, 4].iter() {
+        let free = _DISTINCT_KEYS(r_p, i, &pools[i].1)
+            .into_iter()
+            .filter(|(key, _)| !used.contains(key))
+            .collect::<Vec<_>>();
+        let cnt = pools[i].0.to_usize().unwrap();
+        if cnt > free.len() {
+            return None;
+        }
+
+        for idx in rand::seq::index::sample(rng, free.len(), cnt).into_iter() {
+            let (key, ch) = &free[idx];
+            used.insert(*key);
+            PWD.push(*ch);
+        }
+    }
+
+    Some(PWD)
+
+}
+
+
+/// The chunks of random password, the chunks come from `_DIV_UNIT`,
+/// the prefix and the suffix are chunks of their own
+///
+/// Instead of shuffling the whole password, the category of every position is drawn
+/// with the probability of its remaining count, which gives a uniformly shuffled password
+/// the same way, so only one chunk is ever held in memory. A chunk is made of whole
+/// characters, so its bytes are valid UTF-8 on their own.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn _CHUNKS<'a, R: Rng + 'a>(r_p: &'a RandPwd, mut rng: R) -> Result<impl Iterator<Item = String> + 'a, PasswordError> {
+
+    let mut whole = match r_p.mode {
+        Mode::Passphrase { word_cnt, separator } => Some(_PHRASE(r_p, word_cnt, separator, &mut rng)),
+        Mode::Pronounceable => Some(_SYLLABLES(r_p, &mut rng)),
+        Mode::Groups => Some(_GROUPS(r_p, &mut rng).into_iter().collect()),
+        Mode::Chars => None,
+    };
+
+    let pools = if whole.is_none() { _DRAW_POOLS(r_p, &mut rng) } else { Vec::new() };
+    let mut remaining = pools
+        .iter()
+        .map(|(cnt, _)| cnt.to_u128().ok_or(PasswordError::InvalidCount("len")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let total = pools.iter().map(|(cnt, _)| cnt).sum::<BigUint>();
+    let mut distinct = (0..pools.len()).map(|i| _DISTINCT_IDX(r_p, i, pools[i].1.len(), &mut rng)).collect::<Vec<_>>();
+    let mut plan = _DIV_UNIT(r_p._UNIT, &total).into_iter();
+    let mut offset = 0;
+
+    let body = core::iter::from_fn(move || {
+        if let Some(PWD) = whole.take() {
+            let mut PWD = PWD.chars().collect::<Vec<_>>();
+            _CASE(r_p, &mut PWD, 0);
+            _LEET(r_p, &mut PWD, &mut rng);
+            return Some(PWD.into_iter().collect());
+        }
+
+        let chunk = plan.next()?;
+        let mut buf = Vec::with_capacity(chunk);
+        for _ in 0..chunk {
+            let kind = if r_p.shuffle {
+                let mut r = rng.gen_range(0, remaining.iter().sum::<u128>());
+                remaining.iter().position(|cnt| if r < *cnt { true } else { r -= cnt; false }).unwrap()
+            } else {
+                remaining.iter().position(|cnt| *cnt > 0).unwrap()
+            };
+            remaining[kind] -= 1;
+
+            let pool = &pools[kind].1;
+            let idx = distinct[kind].pop().unwrap_or_else(|| _WEIGHTED_IDX(r_p, 1, pool, &mut rng)[0]);
+            buf.push(pool[idx]);
+        }
+        _CASE(r_p, &mut buf, offset);
+        _LEET(r_p, &mut buf, &mut rng);
+        offset += chunk;
+
+        Some(buf.into_iter().collect())
+    });
+
+    Ok(core::iter::once(r_p.prefix.clone())
+        .chain(body)
+        .chain(core::iter::once(r_p.suffix.clone()))
+        .filter(|chunk| !chunk.is_empty()))
+
+}
+
+
+/// Binomial coefficient `n choose k`
+#[inline]
+pub(crate) fn _BINOMIAL(n: usize, k: usize) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+    // Every partial product is itself a binomial coefficient, so the division is exact
+    (0..k.min(n - k)).fold(BigUint::one(), |acc, i| acc * (n - i) / (i + 1))
+}
+
+
+/// Generate `ltr + sbl + num` random bytes
+#[inline]
+pub(crate) fn _BYTES<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Vec<u8> {
+
+    let len = &r_p.ltr_cnt + &r_p.sbl_cnt + &r_p.num_cnt;
+    let mut bytes = vec![0; len.to_usize().unwrap()];
+    rng.fill_bytes(&mut bytes);
+
+    bytes
+
+}
+
+
+/// The words to draw passphrases from
+#[inline]
+pub(crate) fn _WORDS(r_p: &RandPwd) -> Vec<&str> {
+    match &r_p.wordlist {
+        Some(words) => words.iter().map(String::as_str).collect(),
+        None        => WORDLIST.to_vec(),
+    }
+}
+
+
+/// Generate random passphrase with `word_cnt` words joined by `separator`
+#[inline]
+pub(crate) fn _PHRASE<R: Rng>(r_p: &RandPwd, word_cnt: usize, separator: char, rng: &mut R) -> String {
+
+    let words = _WORDS(r_p);
+
+    _RAND_IDX(word_cnt, words.len(), rng)
+        .iter()
+        .map(|idx| words[*idx])
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+
+}
+
+
+/// The consonants and vowels of pronounceable passwords, without `AMBIGUOUS` if excluded
+/// and without the characters of `RandPwd::exclude_chars`
+#[inline]
+pub(crate) fn _SYLLABLE_POOLS(r_p: &RandPwd) -> (Vec<char>, Vec<char>) {
+    let pool = |s: &str| {
+        s.chars()
+            .filter(|ch| !(r_p.exclude_ambiguous && AMBIGUOUS.contains(*ch)))
+            .filter(|ch| !r_p.excluded.contains(*ch))
+            .collect()
+    };
+    (pool(CONSONANTS), pool(VOWELS))
+}
+
+
+/// Generate random pronounceable password, the letters alternate between consonants and vowels
+/// from a random start, then every symbol and number is inserted at a random position
+#[inline]
+pub(crate) fn _SYLLABLES<R: Rng>(r_p: &RandPwd, rng: &mut R) -> String {
+
+    let (consonants, vowels) = _SYLLABLE_POOLS(r_p);
+    let pools = _DRAW_POOLS(r_p, rng);
+    let start = rng.gen_range(0, 2);
+
+    let mut PWD = (0..(&pools[0].0 + &pools[1].0 + &pools[2].0).to_usize().unwrap())
+        .map(|i| {
+            let pool = if (i + start) % 2 == 0 { &consonants } else { &vowels };
+            pool[rng.gen_range(0, pool.len())]
+        })
+        .collect::<Vec<_>>();
+
+    // Inserting one by one at uniform positions gives uniform positions for all of them
+    for (cnt, pool) in &pools[3..] {
+        for _ in 0..cnt.to_usize().unwrap() {
+            let pos = rng.gen_range(0, PWD.len() + 1);
+            PWD.insert(pos, pool[rng.gen_range(0, pool.len())]);
+        }
+    }
+
+    PWD.into_iter().collect()
+
+}
+
+
+/// Compare `a` with `b`, in constant time with the `subtle` feature, see `RandPwd::ct_eq`
+#[inline]
+pub(crate) fn _CT_EQ(a: &str, b: &str) -> bool {
+    #[cfg(feature = "subtle")]
+    return a.as_bytes().ct_eq(b.as_bytes()).into();
+    #[cfg(not(feature = "subtle"))]
+    return a == b;
+}
+
+
+/// Wipe `buf` but keep its allocation, see `RandPwd::clear`
+#[inline]
+pub(crate) fn _WIPE(buf: &mut String) {
+    #[cfg(feature = "zeroize")]
+    buf.zeroize();
+    #[cfg(not(feature = "zeroize"))]
+    buf.clear();
+}
+
+
+/// Wipe `buf` like `_WIPE`, for the characters and the indexes drawn on the way
+#[cfg(feature = "zeroize")]
+#[inline]
+pub(crate) fn _WIPE_VEC<T: Zeroize>(buf: &mut Vec<T>) {
+    buf.zeroize();
+}
+
+
+/// Wipe `buf` like `_WIPE`, for the characters and the indexes drawn on the way
+#[cfg(not(feature = "zeroize"))]
+#[inline]
+pub(crate) fn _WIPE_VEC<T>(buf: &mut Vec<T>) {
+    buf.clear();
+}
+
+
+/// Split `pwd` into its characters and wipe it
+#[inline]
+pub(crate) fn _TAKE_CHARS(mut pwd: String) -> Vec<char> {
+    // At least as many bytes as characters, so `chars` never grows
+    let mut chars = Vec::with_capacity(pwd.len());
+    chars.extend(pwd.chars());
+    _WIPE(&mut pwd);
+    chars
+}
+
+
+/// Draw every group of `RandPwd::from_groups` from its alphabet and shuffle them together
+#[inline]
+pub(crate) fn _GROUPS<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Vec<char> {
+
+    let mut PWD = r_p.groups
         .iter()
-        .map(|(bignum, data)| {
-            _DIV_UNIT(unit, *bignum)
-                .par_iter()
-                .map(|cnt| {
-                    _RAND_IDX(*cnt, data.len())
-                        .par_iter()
-                        // TODO: - Remove this `clone` which can cause huge overhead of both memory and CPU
-                        .map(|idx| data[*idx].clone())
-                        .collect::<String>()
-                })
-                .collect()
+        .flat_map(|(alphabet, cnt)| {
+            let chars = alphabet.chars().collect::<Vec<_>>();
+            _RAND_IDX(*cnt, chars.len(), rng).into_iter().map(move |idx| chars[idx])
         })
-        .collect::<Vec<Vec<_>>>()
-        .concat()
-        .join("")
+        .collect::<Vec<_>>();
+    if r_p.shuffle {
+        PWD.shuffle(rng);
+    }
+
+    PWD
+
+}
+
+
+/// Generate random password and shuffle it, or generate random passphrase
+#[inline]
+pub(crate) fn _JOIN<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Result<String, PasswordError> {
+    let mut PWD = String::new();
+    _JOIN_INTO(r_p, rng, &mut PWD)?;
+
+    Ok(PWD)
+}
+
+
+/// Generate like `_JOIN` into `buf`, which is wiped first so its allocation is reused
+///
+/// The post-processing runs in the order of the shuffle, the fixups of `no_repeats`
+/// and `no_sequences`, `_CASE`, `_LEET` which keeps both of them, the checksum,
+/// then the prefix and the suffix around it all.
+#[inline]
+pub(crate) fn _JOIN_INTO<R: Rng>(r_p: &RandPwd, rng: &mut R, buf: &mut String) -> Result<(), PasswordError> {
+
+    _WIPE(buf);
+
+    let mut PWD = match r_p.mode {
+        Mode::Passphrase { word_cnt, separator } => _TAKE_CHARS(_PHRASE(r_p, word_cnt, separator, rng)),
+        Mode::Pronounceable => _TAKE_CHARS(_SYLLABLES(r_p, rng)),
+        Mode::Groups => _GROUPS(r_p, rng),
+        Mode::Chars => _SHUFFLED(r_p, rng)?,
+    };
+    _CASE(r_p, &mut PWD, 0);
+    _LEET(r_p, &mut PWD, rng);
+    if let Some(kind) = r_p.checksum {
+        // A new allocation rather than a grown one, which would leave the old one behind unwiped
+        let mut check = kind.compute(r_p, &PWD).ok_or(PasswordError::EmptyPool("checksum"))?;
+        let mut checked = Vec::with_capacity(PWD.len() + check.len());
+        checked.extend(&PWD);
+        checked.extend(&check);
+        _WIPE_VEC(&mut PWD);
+        _WIPE_VEC(&mut check);
+        PWD = checked;
+    }
+
+    // Reserved up front, so no bigger allocation leaves a copy of the password behind
+    buf.reserve(r_p.prefix.len() + PWD.iter().map(|ch| ch.len_utf8()).sum::<usize>() + r_p.suffix.len());
+    buf.push_str(&r_p.prefix);
+    buf.extend(PWD.iter());
+    buf.push_str(&r_p.suffix);
+    _WIPE_VEC(&mut PWD);
+    Ok(())
+
+}
+
+
+/// Draw again the characters of the category `kind` of the content, in the order of letters,
+/// symbols and numbers, and put them at the positions of the old ones
+///
+/// The positions are told apart by the pools, the prefix, the suffix and the check characters
+/// are left out of them. A content which matched the policy
+/// gets a new one which matches it too, or the draws fail.
+#[inline]
+pub(crate) fn _REROLL<R: Rng>(r_p: &RandPwd, kind: usize, rng: &mut R) -> Result<String, PasswordError> {
+
+    // The groups can't be told apart by the pools
+    if r_p.mode == Mode::Groups {
+        return Err(PasswordError::ConstraintUnsatisfiable("reroll"));
+    }
+
+    let sets = _POOL_SETS(r_p);
+    let pools = _POOLS(r_p);
+    let chars = r_p.content.chars().collect::<Vec<_>>();
+    // The prefix and the suffix are kept, the check characters are computed again
+    let start = r_p.prefix.chars().count().min(chars.len());
+    let check_len = r_p.checksum.map_or(0, CheckKind::len);
+    let body = chars.len().saturating_sub(r_p.suffix.chars().count() + check_len).max(start);
+    let positions = (start..body)
+        .filter(|&i| match kind {
+            0 => !sets[3].contains(&chars[i]) && !sets[4].contains(&chars[i]) && sets[..3].iter().any(|set| set.contains(&chars[i])),
+            _ => sets[kind + 2].contains(&chars[i]),
+        })
+        .collect::<Vec<_>>();
+    let matched = r_p.matches_policy(&r_p.content);
+
+    for _ in 0..MAX_DRAWS {
+        let n = positions.len();
+        // The guaranteed uppercase and lowercase letters are drawn first, as far as there are letters
+        let cnts = match kind {
+            0 => {
+                let upper = r_p.min_upper.to_usize().unwrap().min(n);
+                let lower = r_p.min_lower.to_usize().unwrap().min(n - upper);
+                vec![(0, n - upper - lower), (1, upper), (2, lower)]
+            }
+            _ => vec![(kind + 2, n)],
+        };
+        let mut drawn = cnts
+            .into_iter()
+            .flat_map(|(i, cnt)| {
+                let pool = &pools[i].1;
+                let mut idxs = _WEIGHTED_IDX(r_p, cnt, pool, rng);
+                idxs.iter_mut().zip(_DISTINCT_IDX(r_p, i, pool.len(), rng)).for_each(|(idx, distinct)| *idx = distinct);
+                idxs.into_iter().map(move |idx| pool[idx])
+            })
+            .collect::<Vec<_>>();
+        drawn.shuffle(rng);
+
+        let mut PWD = chars.clone();
+        for (&pos, ch) in positions.iter().zip(drawn) {
+            PWD[pos] = if kind == 0 { r_p.case.map(ch, pos - start) } else { ch };
+        }
+        if let Some(check) = r_p.checksum {
+            let sum = check.compute(r_p, &PWD[start..body]).ok_or(PasswordError::EmptyPool("checksum"))?;
+            PWD.splice(body..(body + check_len).min(PWD.len()), sum);
+        }
+        let mut PWD = PWD.into_iter().collect::<String>();
+
+        if !matched || r_p.matches_policy(&PWD) {
+            return Ok(PWD);
+        }
+        _WIPE(&mut PWD);
+    }
+
+    Err(PasswordError::ConstraintUnsatisfiable("reroll"))
+
+}
+
+
+/// Normalize the case of every character of `pwd` which isn't a symbol or a number,
+/// `pwd` starts at the position `offset` of the password
+#[inline]
+pub(crate) fn _CASE(r_p: &RandPwd, pwd: &mut [char], offset: usize) {
+
+    if r_p.case == CasePolicy::AsIs { return; }
+
+    let sets = _POOL_SETS(r_p);
+    pwd.iter_mut()
+        .enumerate()
+        .filter(|(_, ch)| !sets[3].contains(ch) && !sets[4].contains(ch))
+        .for_each(|(i, ch)| *ch = r_p.case.map(*ch, offset + i));
+
+}
+
+
+/// Substitute the letters of `LEET` with the probability set by `RandPwd::set_leetify`,
+/// a substitution that would break `no_repeats`, `no_sequences`, `no_edge_symbols` or `all_distinct` is skipped
+#[inline]
+pub(crate) fn _LEET<R: Rng>(r_p: &RandPwd, pwd: &mut [char], rng: &mut R) {
+
+    // Leave the generator untouched, so seeded passwords don't change
+    if r_p.leet == 0.0 { return; }
+
+    for i in 0..pwd.len() {
+        let leet = match LEET.iter().find(|(ch, _)| *ch == pwd[i]) {
+            Some(&(_, leet)) => leet,
+            None             => continue,
+        };
+        if !rng.gen_bool(r_p.leet) { continue; }
+
+        let ch = mem::replace(&mut pwd[i], leet);
+        let repeats = r_p.no_repeats && ((i > 0 && pwd[i - 1] == leet) || (i + 1 < pwd.len() && pwd[i + 1] == leet));
+        let sequences = r_p.no_sequences && (i.max(2)..pwd.len().min(i + 3)).any(|k| _IS_SEQUENCE(pwd, k));
+        let edge = r_p.no_edge_symbols && (i == 0 || i + 1 == pwd.len()) && !leet.is_alphanumeric();
+        let distinct = r_p.all_distinct && pwd.iter().enumerate().any(|(j, other)| j != i && *other == leet);
+        if repeats || sequences || edge || distinct {
+            pwd[i] = ch;
+        }
+    }
 
 }
 
 
+/// Generate random password and shuffle it, honoring `no_repeats` and `no_sequences`,
+/// the constraint that failed is returned after `MAX_DRAWS` passwords
+///
+/// Letters alone, like the ones of `RandPwd::from_alphabet`, are drawn straight from their pool.
+#[inline]
+pub(crate) fn _SHUFFLED<R: Rng>(r_p: &RandPwd, rng: &mut R) -> Result<Vec<char>, PasswordError> {
+
+    // Only the letters without constraints need neither `_PWD` nor the shuffle
+    let single = r_p.sbl_cnt.is_zero() && r_p.num_cnt.is_zero() && r_p.min_upper.is_zero() && r_p.min_lower.is_zero();
+    if single && !r_p.no_repeats && !r_p.no_sequences && !r_p.all_distinct {
+        if let Some(n) = r_p.ltr_cnt.to_usize() {
+            let pool = &_POOLS(r_p)[0].1;
+            return Ok(_WEIGHTED_IDX(r_p, n, pool, rng).into_iter().map(|idx| pool[idx]).collect());
+        }
+    }
+
+    let mut failed = "no_repeats";
+
+    for _ in 0..MAX_DRAWS {
+        // Shuffle the `char`s rather than the bytes, so multi-byte characters stay intact
+        let mut PWD = match &r_p.pattern {
+            Some(pattern) => _PATTERNED(r_p, pattern, rng),
+            None if r_p.all_distinct => _ALL_DISTINCT(r_p, rng).ok_or(PasswordError::ConstraintUnsatisfiable("all_distinct"))?,
+            None          => _TAKE_CHARS(_PWD(r_p, rng)),
+        };
+        if r_p.shuffle {
+            _SPAN!("shuffle", PWD.shuffle(rng));
+        }
+
+        // A rejected password is wiped before the next one is drawn
+        match _REJECTED(r_p, &mut PWD, rng) {
+            Some(name) => {
+                failed = name;
+                _WIPE_VEC(&mut PWD);
+            }
+            None => return Ok(PWD),
+        }
+    }
+
+    Err(PasswordError::ConstraintUnsatisfiable(failed))
+
+}
+
+
+/// Fix up the repeats, the sequences and the symbols on the edges of `pwd` in place,
+/// or return the constraint that is still broken
+#[inline]
+pub(crate) fn _REJECTED<R: Rng>(r_p: &RandPwd, pwd: &mut [char], rng: &mut R) -> Option<&'static str> {
+
+    // Draw again if the repeats or the sequences can't be broken up by swapping,
+    // or breaking up the sequences brought the repeats back
+    if r_p.no_repeats && !_BREAK_REPEATS(pwd) { return Some("no_repeats"); }
+    if r_p.no_sequences && !_BREAK_SEQUENCES(pwd, rng) { return Some("no_sequences"); }
+    if r_p.no_repeats && pwd.windows(2).any(|w| w[0] == w[1]) { return Some("no_repeats"); }
+
+    // Moving the symbols off the edges may bring the repeats or the sequences back
+    if r_p.no_edge_symbols {
+        if !_CLEAR_EDGES(pwd, &_POOL_SETS(r_p)[3]) { return Some("no_edge_symbols"); }
+        if r_p.no_repeats && pwd.windows(2).any(|w| w[0] == w[1]) { return Some("no_repeats"); }
+        if r_p.no_sequences && (2..pwd.len()).any(|i| _IS_SEQUENCE(pwd, i)) { return Some("no_sequences"); }
+    }
+
+    None
+
+}
+
+
+/// A 16 characters password of 12 letters, 2 symbols and 2 numbers
+///
+/// That's the usual baseline of the password policies rather than a recommendation,
+/// it has about 85 bits of entropy with the default `Charset`. Nothing is generated until `join`.
+/// ```
+/// use rand_pwd::{ RandPwd, Strength };
+/// let mut r_p = RandPwd::default();
+/// r_p.join();
+/// assert_eq!(r_p.val().len(), 16);
+/// assert_eq!(r_p.strength(), Strength::Strong);
+/// assert_eq!(r_p.entropy_bits().round(), 85.0);
+/// ```
 impl Default for RandPwd {
 
     #[inline]
     fn default() -> Self {
-        RandPwd::new(0, 0, 0)
+        RandPwd::new(12, 2, 2).unwrap()
     }
 
 }
@@ -163,8 +1145,64 @@ impl Default for RandPwd {
 impl Display for RandPwd {
 
     #[inline]
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(f, "\n{}", self.content)
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.bidi_safe && !self.content.is_empty() {
+            write!(f, "\u{2066}{}\u{2069}", self.content)
+        } else {
+            write!(f, "{}", self.content)
+        }
+    }
+
+}
+
+
+/// Parse a configuration like `"16:4:2"` as the counts of letters, symbols and numbers
+/// ```
+/// use rand_pwd::{ RandPwd, PasswordError };
+/// let r_p = "16:4:2".parse::<RandPwd>().unwrap();
+/// assert_eq!(r_p.get_cnt("sbl").unwrap(), &4u8.into());
+///
+/// assert_eq!("16:4".parse::<RandPwd>().unwrap_err(), PasswordError::Parse("16:4".to_string()));
+/// ```
+impl FromStr for RandPwd {
+
+    type Err = PasswordError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cnts = s
+            .split(':')
+            .map(|cnt| cnt.trim().parse::<BigUint>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| PasswordError::Parse(s.to_string()))?;
+
+        match &cnts[..] {
+            [ltr_cnt, sbl_cnt, num_cnt] => RandPwd::new(ltr_cnt.clone(), sbl_cnt.clone(), num_cnt.clone()),
+            _ => Err(PasswordError::Parse(s.to_string())),
+        }
+    }
+
+}
+
+
+/// Take the counts of letters, symbols and numbers, see `RandPwd::new`
+///
+/// A `&str` already converts with `From`, which counts the categories of a password,
+/// so a configuration like `"16:4:2"` is parsed by `FromStr`.
+/// ```
+/// use rand_pwd::RandPwd;
+/// use std::convert::{ TryFrom, TryInto };
+/// let r_p = RandPwd::try_from((10, 2, 3)).unwrap();
+/// assert_eq!(r_p.length(), 15u8.into());
+///
+/// let r_p: RandPwd = (u64::MAX, 0, 0).try_into().unwrap();
+/// assert_eq!(r_p.letter_count(), &u64::MAX.into());
+/// ```
+impl TryFrom<(u64, u64, u64)> for RandPwd {
+
+    type Error = PasswordError;
+    #[inline]
+    fn try_from((ltr_cnt, sbl_cnt, num_cnt): (u64, u64, u64)) -> Result<Self, Self::Error> {
+        RandPwd::new(ltr_cnt, sbl_cnt, num_cnt)
     }
 
 }
@@ -174,14 +1212,13 @@ impl Add for RandPwd {
 
     type Output = Self;
     #[inline]
-    fn add(self, rhs: Self) -> Self {
-        RandPwd {
-            ltr_cnt: self.ltr_cnt + rhs.ltr_cnt,
-            sbl_cnt: self.sbl_cnt + rhs.sbl_cnt,
-            num_cnt: self.num_cnt + rhs.num_cnt,
-            content: self.content + &rhs.content,
-            _UNIT: 1,
-        }
+    fn add(mut self, rhs: Self) -> Self {
+        let mut r_p = RandPwd::new(&self.ltr_cnt + &rhs.ltr_cnt,
+                                   &self.sbl_cnt + &rhs.sbl_cnt,
+                                   &self.num_cnt + &rhs.num_cnt,).unwrap();
+        r_p.content = mem::take(&mut self.content) + &rhs.content;
+
+        r_p
     }
 }
 
@@ -191,15 +1228,27 @@ impl AddAssign for RandPwd {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
 
-        self.ltr_cnt += rhs.ltr_cnt;
-        self.sbl_cnt += rhs.sbl_cnt;
-        self.num_cnt += rhs.num_cnt;
+        self.ltr_cnt += &rhs.ltr_cnt;
+        self.sbl_cnt += &rhs.sbl_cnt;
+        self.num_cnt += &rhs.num_cnt;
         self.content += &rhs.content;
 
     }
 }
 
 
+/// Wipe the password before the memory is released
+#[cfg(feature = "zeroize")]
+impl Drop for RandPwd {
+
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
+
+}
+
+
 impl AsRef<str> for RandPwd {
 
     #[inline]
@@ -215,9 +1264,9 @@ impl From<&str> for RandPwd {
     #[inline]
     fn from(s: &str) -> Self {
         let (ltr_cnt, sbl_cnt, num_cnt) = _CNT(s);
-        let mut r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt);
+        // `usize` always converts into `BigUint`
+        let mut r_p = RandPwd::new(ltr_cnt, sbl_cnt, num_cnt).unwrap();
         r_p.set_val(s);
-        r_p.set_unit(1);
 
         r_p
     }
@@ -0,0 +1,64 @@
+use num_bigint::BigUint;
+
+
+/// How hard a password is to guess, judged by its entropy
+///
+/// The variants are ordered from the weakest to the strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strength {
+    /// Less than 40 bits
+    Weak,
+    /// From 40 to 80 bits
+    Medium,
+    /// More than 80 bits
+    Strong,
+}
+
+
+impl Strength {
+
+    /// Classify an entropy of `bits`
+    /// ```
+    /// use rand_pwd::Strength;
+    /// assert_eq!(Strength::from_bits(39.9), Strength::Weak);
+    /// assert_eq!(Strength::from_bits(80.0), Strength::Medium);
+    /// assert_eq!(Strength::from_bits(80.1), Strength::Strong);
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: f64) -> Self {
+        if bits < 40.0 {
+            Strength::Weak
+        } else if bits <= 80.0 {
+            Strength::Medium
+        } else {
+            Strength::Strong
+        }
+    }
+
+}
+
+
+/// The entropy of a configuration in bits and as the number of guesses it takes to try every password
+///
+/// `bits` is `RandPwd::entropy_bits`, an `f64` with about 16 significant digits, so past
+/// a few thousand bits the fraction of a bit is lost. `guesses` is `RandPwd::combinations`, exact,
+/// or none when it's too large to count. The shuffle adds the positions of the categories
+/// to `guesses` but not to `bits`, so `log2(guesses)` is at least `bits`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntropyReport {
+    /// The entropy in bits
+    pub bits: f64,
+    /// The number of passwords which can be generated
+    pub guesses: Option<BigUint>,
+}
+
+
+impl EntropyReport {
+
+    /// Classify the entropy, see `Strength::from_bits`
+    #[inline]
+    pub fn strength(&self) -> Strength {
+        Strength::from_bits(self.bits)
+    }
+
+}
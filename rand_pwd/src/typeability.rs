@@ -0,0 +1,84 @@
+use alloc::string::{ String, ToString };
+
+
+/// Which characters `RandPwd::typeability` keeps for passwords typed often
+/// ```
+/// use rand_pwd::{ RandPwd, TypePrefer, Layout, PasswordError };
+/// let mut r_p = RandPwd::new(12, 0, 0).unwrap();
+/// r_p.typeability(TypePrefer::HomeRow, &Layout::qwerty()).unwrap();
+/// r_p.join();
+/// assert!(r_p.val().chars().all(|ch| "asdfghjklASDFGHJKL".contains(ch)));
+/// assert_eq!(r_p.alphabet_size(), 18);
+///
+/// // The home row has no digits
+/// let mut r_p = RandPwd::new(12, 0, 2).unwrap();
+/// assert_eq!(r_p.typeability(TypePrefer::HomeRow, &Layout::qwerty()), Err(PasswordError::EmptyPool("num")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TypePrefer {
+    /// Only the keys of the home row, with or without shift
+    HomeRow,
+    /// Only the characters typed without shift
+    NoShift,
+}
+
+
+/// The keyboard `TypePrefer` is measured on, `Layout::qwerty` unless told otherwise
+/// ```
+/// use rand_pwd::{ RandPwd, TypePrefer, Layout };
+/// // The home row of AZERTY
+/// let azerty = Layout::new("qsdfghjklmùQSDFGHJKLM%", "azertyuiopqsdfghjklmùwxcvbn,;:!&é\"'(-è_çà)=");
+/// let mut r_p = RandPwd::new(12, 0, 0).unwrap();
+/// r_p.typeability(TypePrefer::HomeRow, &azerty).unwrap();
+/// assert_eq!(r_p.alphabet_size(), 20);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    home_row: String,
+    unshifted: String,
+}
+
+
+impl Layout {
+
+    /// Return a layout whose home row types the characters of `home_row`, with or without shift,
+    /// and whose keys type the characters of `unshifted` without shift
+    #[inline]
+    pub fn new(home_row: &str, unshifted: &str) -> Self {
+        Layout { home_row: home_row.to_string(), unshifted: unshifted.to_string() }
+    }
+
+
+    /// Return the US QWERTY layout
+    #[inline]
+    pub fn qwerty() -> Self {
+        Layout::new("asdfghjkl;'ASDFGHJKL:\"", "`1234567890-=qwertyuiop[]\\asdfghjkl;'zxcvbnm,./")
+    }
+
+}
+
+
+impl Default for Layout {
+
+    #[inline]
+    fn default() -> Self {
+        Layout::qwerty()
+    }
+
+}
+
+
+impl TypePrefer {
+
+    /// Whether `ch` is kept on `layout`
+    #[inline]
+    pub(crate) fn keeps(self, layout: &Layout, ch: char) -> bool {
+        match self {
+            TypePrefer::HomeRow => layout.home_row.contains(ch),
+            TypePrefer::NoShift => layout.unshifted.contains(ch),
+        }
+    }
+
+}
@@ -0,0 +1,10 @@
+/// Known passwords of `RandPwd::with_seed` and `join`, as the seed, the counts of letters,
+/// symbols and numbers, and the password
+pub(crate) const TEST_VECTORS: [(u64, (u32, u32, u32), &str); 6] = [
+    (0,        (10, 2, 3),  "FozZ5M1#sJ7BN)u"),
+    (1,        (16, 2, 2),  "(TvgHp,5WOgTgWMg8dPc"),
+    (42,       (8, 0, 0),   "aVFIIRrX"),
+    (7,        (0, 4, 0),   "*<!}"),
+    (2020,     (0, 0, 12),  "809429671182"),
+    (u64::MAX, (20, 6, 6),  "SM4Ac+D2fuQ`cr(EuhSx08q8{l=xik-3"),
+];
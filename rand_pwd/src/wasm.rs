@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use crate::RandPwd;
+use wasm_bindgen::prelude::*;
+
+
+/// `RandPwd` for JavaScript, exported as `RandPwd`
+///
+/// The counts are plain `u32`s and the errors are their `Display` strings.
+/// `thread_rng` isn't available in the browser, so every password is drawn
+/// from a `StdRng` seeded by `getrandom` with its `js` backend, i.e. `crypto.getRandomValues`.
+/// ```js
+/// import { RandPwd } from "rand_pwd";
+/// const r_p = new RandPwd(10, 2, 3);
+/// console.log(r_p.join());
+/// ```
+#[wasm_bindgen(js_name = RandPwd)]
+pub struct WasmPwd(RandPwd);
+
+
+#[wasm_bindgen(js_class = RandPwd)]
+impl WasmPwd {
+
+    /// Return an instance with `ltr_cnt` letters, `sbl_cnt` symbols and `num_cnt` numbers, like `RandPwd::new`
+    #[wasm_bindgen(constructor)]
+    pub fn new(ltr_cnt: u32, sbl_cnt: u32, num_cnt: u32) -> Result<WasmPwd, String> {
+        RandPwd::new(ltr_cnt, sbl_cnt, num_cnt).map(WasmPwd).map_err(|err| err.to_string())
+    }
+
+
+    /// Generate a password, like `RandPwd::join`, and return it
+    pub fn join(&mut self) -> Result<String, String> {
+
+        let mut seed = <StdRng as SeedableRng>::Seed::default();
+        getrandom::getrandom(&mut seed).map_err(|err| err.to_string())?;
+        self.0.try_join_with_rng(&mut StdRng::from_seed(seed)).map_err(|err| err.to_string())?;
+
+        Ok(self.0.val().to_owned())
+
+    }
+
+
+    /// Return the last generated password, like `RandPwd::val`
+    pub fn val(&self) -> String {
+        self.0.val().to_owned()
+    }
+
+}
@@ -0,0 +1,31 @@
+/// Embedded list of 256 short, distinct English words for passphrases,
+/// every word adds exactly 8 bits of entropy
+pub(crate) const WORDLIST: [&str; 256] = [
+    "acid", "acorn", "actor", "adult", "agent", "album", "alien", "amber", "angle", "ankle",
+    "apple", "apron", "arena", "armor", "arrow", "atlas", "attic", "audio", "autumn", "badge",
+    "bagel", "baker", "balmy", "bamboo", "banjo", "barn", "basil", "beach", "beard", "berry",
+    "bison", "blade", "blank", "blaze", "bloom", "board", "bonus", "boost", "brave", "bread",
+    "brick", "bride", "broom", "brush", "bucket", "cabin", "cable", "cactus", "camel", "candy",
+    "canoe", "canvas", "cargo", "carpet", "cedar", "chalk", "charm", "cheek", "chess", "chief",
+    "chili", "cider", "clamp", "cliff", "clock", "cloud", "clown", "coach", "cobra", "comet",
+    "coral", "couch", "crane", "crisp", "crown", "daisy", "dance", "delta", "denim", "depot",
+    "desk", "diary", "dingo", "dizzy", "dock", "dolphin", "donut", "draft", "dragon", "dream",
+    "drift", "drum", "dune", "dusk", "eagle", "earth", "easel", "ebony", "echo", "elbow",
+    "elder", "ember", "entry", "essay", "event", "fable", "fancy", "farm", "feast", "fence",
+    "ferry", "fiber", "field", "flame", "flash", "flock", "flute", "focus", "foggy", "forest",
+    "fossil", "frost", "fudge", "gadget", "galaxy", "garden", "gecko", "giant", "ginger",
+    "glass", "globe", "glove", "goose", "grape", "gravy", "guitar", "gummy", "habit", "hammer",
+    "harbor", "hazel", "heron", "hiking", "honey", "hornet", "hotel", "igloo", "image", "index",
+    "ivory", "jacket", "jelly", "jewel", "jockey", "joker", "juice", "jumbo", "jungle", "kayak",
+    "kettle", "kiosk", "kitten", "koala", "ladder", "lagoon", "lemon", "lilac", "linen",
+    "lizard", "lobster", "lunar", "magnet", "mango", "maple", "marble", "meadow", "melon",
+    "metal", "mint", "mocha", "motel", "mural", "napkin", "nectar", "needle", "nickel", "noble",
+    "noodle", "nutmeg", "oasis", "ocean", "olive", "onion", "opera", "orbit", "otter", "oyster",
+    "paddle", "panda", "parade", "pebble", "pepper", "piano", "pickle", "pilot", "planet",
+    "plaza", "pocket", "polar", "poppy", "quartz", "quilt", "rabbit", "radar", "raisin",
+    "ranch", "raven", "recipe", "ribbon", "river", "robot", "rocket", "rumble", "saddle",
+    "salad", "salmon", "sandal", "satin", "scarf", "shadow", "silver", "sketch", "slope",
+    "snack", "spider", "tablet", "tango", "tavern", "thunder", "tiger", "toast", "tomato",
+    "tulip", "tunnel", "turtle", "velvet", "violin", "voyage", "walnut", "whale", "willow",
+    "wizard", "yacht", "yodel", "zebra", "zipper",
+];
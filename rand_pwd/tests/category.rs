@@ -0,0 +1,66 @@
+#![cfg(all(feature = "std", not(target_arch = "wasm32")))]
+
+use proptest::prelude::*;
+use rand_pwd::RandPwd;
+
+
+/// The counts of letters, symbols and numbers, and a `min_upper` of at most half the letters
+fn counts(max_ltr: usize, max_other: usize) -> impl Strategy<Value = (usize, usize, usize, usize)> {
+    (0..=max_ltr, 0..=max_other, 0..=max_other)
+        .prop_flat_map(|(l, s, n)| (Just(l), Just(s), Just(n), 0..=l / 2))
+}
+
+
+/// Generate a password and check the invariants of the draws and the shuffle
+fn check(r_p: &mut RandPwd, (l, s, n, upper): (usize, usize, usize, usize)) -> Result<(), TestCaseError> {
+
+    r_p.join();
+
+    prop_assert_eq!(r_p.val().chars().count(), l + s + n);
+    prop_assert_eq!(r_p.category_counts(), (l, s, n));
+    prop_assert!(r_p.val().chars().filter(char::is_ascii_uppercase).count() >= upper);
+    prop_assert!(r_p.matches_policy(r_p.val()));
+
+    Ok(())
+
+}
+
+
+proptest! {
+
+    #[test]
+    fn join_keeps_the_counts(counts in counts(40, 10), exclude_ambiguous: bool, shuffle: bool, seed: u64) {
+
+        let (l, s, n, upper) = counts;
+        let mut r_p = RandPwd::builder()
+            .letters(l).symbols(s).numbers(n)
+            .min_upper(upper)
+            .exclude_ambiguous(exclude_ambiguous)
+            .shuffle(shuffle)
+            .seed(seed)
+            .build()
+            .unwrap();
+
+        check(&mut r_p, counts)?;
+
+    }
+
+
+    // Above `SMALL_LEN` the pools are drawn in chunks, on rayon when `parallel`
+    #[test]
+    fn join_in_chunks_keeps_the_counts(counts in counts(2000, 300), parallel: bool, seed: u64) {
+
+        let (l, s, n, upper) = counts;
+        let mut r_p = RandPwd::builder()
+            .letters(l).symbols(s).numbers(n)
+            .min_upper(upper)
+            .parallel(parallel)
+            .seed(seed)
+            .build()
+            .unwrap();
+
+        check(&mut r_p, counts)?;
+
+    }
+
+}
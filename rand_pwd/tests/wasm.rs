@@ -0,0 +1,26 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use rand_pwd::WasmPwd;
+use wasm_bindgen_test::*;
+
+
+#[wasm_bindgen_test]
+fn join_in_wasm() {
+
+    let mut r_p = WasmPwd::new(10, 2, 3).unwrap();
+    let pwd = r_p.join().unwrap();
+
+    assert_eq!(pwd.chars().count(), 15);
+    assert_eq!(r_p.val(), pwd);
+    assert_ne!(r_p.join().unwrap(), pwd);
+
+}
+
+
+#[wasm_bindgen_test]
+fn zero_counts() {
+
+    let mut r_p = WasmPwd::new(0, 0, 0).unwrap();
+    assert_eq!(r_p.join().unwrap(), "");
+
+}